@@ -0,0 +1,45 @@
+//! Regenerates `src/table/generated.rs` from `data/PeriodicTableJSON.json`
+//!
+//! Run with `RUSTFLAGS="--cfg chemistru_bootstrap" cargo run --example generate_table`
+//! from the crate root. The cfg swaps the table for an empty one, so the crate
+//! still compiles when the data structures have changed since generation.
+
+use std::fs;
+use std::process::Command;
+
+use chemistru_elements::codegen;
+use proc_macro2::{Ident, Span};
+use quote::quote;
+
+const INPUT: &str = "data/PeriodicTableJSON.json";
+const OUTPUT: &str = "src/table/generated.rs";
+
+fn main() {
+    let elements = codegen::read_elements(INPUT).expect("Failed to read element data");
+
+    let table = codegen::generate(&elements);
+
+    let constants = elements.iter().map(|e| {
+        let ident = Ident::new(&e.name.to_uppercase(), Span::call_site());
+        let symbol = Ident::new(e.symbol, Span::call_site());
+
+        quote! {
+            pub const #ident: chemistru_elements::element::Element =
+                chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::#symbol);
+        }
+    });
+
+    let tokens = quote! {
+        #table
+
+        #(#constants)*
+    };
+
+    fs::write(OUTPUT, codegen::with_header(INPUT, tokens)).expect("Failed to write element table");
+
+    // Formatting is best-effort, the generated code is valid either way. The
+    // wider line width stops rustfmt bailing out on long descriptions.
+    let _ = Command::new("rustfmt")
+        .args(["--edition", "2021", "--config", "max_width=120", OUTPUT])
+        .status();
+}
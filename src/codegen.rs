@@ -0,0 +1,125 @@
+//! Code generation for custom element tables
+//!
+//! Reads Bowserinator-style JSON (as used by
+//! [Periodic-Table-JSON](https://github.com/Bowserinator/Periodic-Table-JSON))
+//! through [`RawElement`] and writes Rust source containing a static
+//! `ELEMENTS` array of [`InnerElement`] and `BY_SYMBOL` and `BY_NAME` lookup
+//! maps from lowercase strings to indices into it.
+//!
+//! The built-in [`table`](crate::table) is generated the same way.
+//!
+//! ```no_run
+//! // main function of build.rs
+//! chemistru_elements::codegen::build_script("data/elements.json", "elements.rs").unwrap();
+//! ```
+//!
+//! ```ignore
+//! // src/elements.rs
+//! include!(concat!(env!("OUT_DIR"), "/elements.rs"));
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use serde::Deserialize;
+
+use crate::error::Result;
+use crate::inner::InnerElement;
+use crate::raw::RawElement;
+
+/// Accepted layouts of the JSON input, either the top level object from
+/// Periodic-Table-JSON or a bare array of elements
+#[derive(Deserialize)]
+#[serde(untagged, bound(deserialize = "'de: 'static"))]
+enum RawTable {
+    Wrapped { elements: Vec<RawElement> },
+    Bare(Vec<RawElement>),
+}
+
+/// Parse elements from JSON
+///
+/// Input has to be static as [`RawElement`] borrows its strings from it
+pub fn parse_json(json: &'static str) -> serde_json::Result<Vec<RawElement>> {
+    Ok(match serde_json::from_str(json)? {
+        RawTable::Wrapped { elements } => elements,
+        RawTable::Bare(elements) => elements,
+    })
+}
+
+/// Generate the items of a static element table
+///
+/// The lookup maps hold indices into `ELEMENTS`
+pub fn generate(elements: &[InnerElement]) -> TokenStream {
+    let count = Literal::usize_unsuffixed(elements.len());
+
+    let indices = (0..elements.len())
+        .map(Literal::usize_unsuffixed)
+        .collect::<Vec<_>>();
+    let symbols = elements.iter().map(|e| e.symbol.to_lowercase());
+    let names = elements.iter().map(|e| e.name.to_lowercase());
+
+    quote! {
+        use chemistru_elements::phf;
+
+        /// Detailed information of every element, in the order they were read
+        pub static ELEMENTS: [&chemistru_elements::inner::InnerElement; #count] = [#(#elements),*];
+
+        /// Indices into `ELEMENTS` keyed by lowercase symbol
+        pub static BY_SYMBOL: phf::Map<&'static str, usize> = phf::phf_map! {
+            #(#symbols => #indices),*
+        };
+
+        /// Indices into `ELEMENTS` keyed by lowercase name
+        pub static BY_NAME: phf::Map<&'static str, usize> = phf::phf_map! {
+            #(#names => #indices),*
+        };
+    }
+}
+
+/// Read elements from a JSON file
+pub fn read_elements(input: impl AsRef<Path>) -> Result<Vec<InnerElement>> {
+    let json = fs::read_to_string(input)?;
+    let json: &'static str = Box::leak(json.into_boxed_str());
+
+    parse_json(json)?
+        .into_iter()
+        .map(RawElement::into_inner)
+        .collect()
+}
+
+/// Read elements from a JSON file and write the generated table to `output`
+pub fn write_table(input: impl AsRef<Path>, output: impl AsRef<Path>) -> Result<()> {
+    let input = input.as_ref();
+
+    let tokens = generate(&read_elements(input)?);
+
+    Ok(fs::write(output, with_header(input, tokens))?)
+}
+
+/// Prefix generated source with a note that it should not be edited
+pub fn with_header(input: impl AsRef<Path>, tokens: TokenStream) -> String {
+    format!(
+        "// @generated by chemistru-elements from {}, do not edit by hand\n\n{tokens}\n",
+        input.as_ref().display()
+    )
+}
+
+/// Build script helper which writes the generated table to `OUT_DIR`
+///
+/// Cargo is told to rerun the build script whenever `input` changes. Returns
+/// the path of the generated file.
+pub fn build_script(input: impl AsRef<Path>, file_name: &str) -> Result<PathBuf> {
+    let out_dir = std::env::var_os("OUT_DIR")
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "OUT_DIR is not set"))?;
+
+    let output = Path::new(&out_dir).join(file_name);
+
+    println!("cargo:rerun-if-changed={}", input.as_ref().display());
+
+    write_table(input, &output)?;
+
+    Ok(output)
+}
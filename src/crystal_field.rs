@@ -0,0 +1,236 @@
+//! Crystal field splitting of the d electrons of transition metal ions
+//!
+//! Energies are relative to the barycentre of the d orbitals, in units of the
+//! splitting of the geometry, Δ_o for octahedral and square planar complexes
+//! and Δ_t for tetrahedral ones.
+//!
+//! ```
+//! use chemistru_elements::crystal_field::{CrystalField, FieldStrength, Geometry};
+//! use chemistru_elements::ion::Ion;
+//! use chemistru_elements::table::COBALT;
+//!
+//! let cobalt = Ion { element: COBALT, oxidation_state: 3 };
+//! let field = CrystalField::new(&cobalt, Geometry::Octahedral, FieldStrength::Strong).unwrap();
+//!
+//! assert_eq!(field.to_string(), "t2g6 eg0");
+//! assert_eq!(field.unpaired_electrons(), 0);
+//! assert!((field.cfse() + 2.4).abs() < 1e-9);
+//! ```
+
+use std::fmt;
+
+use crate::data::prelude::SubshellKind;
+use crate::element::Elemental;
+use crate::error::{Error, Result};
+use crate::ion::Ion;
+
+/// Arrangement of ligands around the metal
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Geometry {
+    Octahedral,
+    Tetrahedral,
+    SquarePlanar,
+}
+
+/// Strength of the ligand field relative to the pairing energy
+///
+/// In a weak field electrons occupy higher orbitals before pairing, giving a
+/// high spin complex. In a strong field the lower orbitals are filled first,
+/// giving a low spin complex.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum FieldStrength {
+    Weak,
+    Strong,
+}
+
+/// Set of degenerate d orbitals
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OrbitalSet {
+    /// Symmetry label, e.g. `t2g`
+    pub label: &'static str,
+    /// Number of orbitals in the set
+    pub orbitals: u8,
+    /// Energy of each orbital relative to the barycentre, in units of Δ
+    pub energy: f64,
+    pub electrons: u8,
+}
+
+impl OrbitalSet {
+    const fn empty(label: &'static str, orbitals: u8, energy: f64) -> Self {
+        Self {
+            label,
+            orbitals,
+            energy,
+            electrons: 0,
+        }
+    }
+
+    /// Number of electrons without a partner of opposite spin
+    pub const fn unpaired_electrons(&self) -> u8 {
+        match self.electrons <= self.orbitals {
+            true => self.electrons,
+            false => 2 * self.orbitals - self.electrons,
+        }
+    }
+
+    /// Number of orbitals holding two electrons
+    pub const fn pairs(&self) -> u8 {
+        self.electrons.saturating_sub(self.orbitals)
+    }
+}
+
+impl Geometry {
+    /// Orbital sets of the geometry, ordered by increasing energy
+    pub const fn orbital_sets(&self) -> &'static [OrbitalSet] {
+        const OCTAHEDRAL: [OrbitalSet; 2] = [
+            OrbitalSet::empty("t2g", 3, -0.4),
+            OrbitalSet::empty("eg", 2, 0.6),
+        ];
+
+        const TETRAHEDRAL: [OrbitalSet; 2] = [
+            OrbitalSet::empty("e", 2, -0.6),
+            OrbitalSet::empty("t2", 3, 0.4),
+        ];
+
+        const SQUARE_PLANAR: [OrbitalSet; 4] = [
+            OrbitalSet::empty("eg", 2, -0.514),
+            OrbitalSet::empty("a1g", 1, -0.428),
+            OrbitalSet::empty("b2g", 1, 0.228),
+            OrbitalSet::empty("b1g", 1, 1.228),
+        ];
+
+        match self {
+            Self::Octahedral => &OCTAHEDRAL,
+            Self::Tetrahedral => &TETRAHEDRAL,
+            Self::SquarePlanar => &SQUARE_PLANAR,
+        }
+    }
+}
+
+/// Distribution of the d electrons of an ion over the orbital sets of a
+/// geometry
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrystalField {
+    pub geometry: Geometry,
+    /// Whether the electrons were placed by the high spin rule
+    pub high_spin: bool,
+    /// Orbital sets in increasing energy, with their electrons
+    pub sets: Vec<OrbitalSet>,
+}
+
+impl CrystalField {
+    /// Crystal field of a transition metal ion
+    ///
+    /// ```
+    /// use chemistru_elements::crystal_field::{CrystalField, FieldStrength, Geometry};
+    /// use chemistru_elements::ion::Ion;
+    /// use chemistru_elements::table::{MOLYBDENUM, PLATINUM, RUTHENIUM};
+    ///
+    /// let field = |element, oxidation_state, geometry| {
+    ///     let ion = Ion { element, oxidation_state };
+    ///
+    ///     CrystalField::new(&ion, geometry, FieldStrength::Strong).unwrap().to_string()
+    /// };
+    ///
+    /// assert_eq!(field(MOLYBDENUM, 6, Geometry::Octahedral), "t2g0 eg0");
+    /// assert_eq!(field(RUTHENIUM, 2, Geometry::Octahedral), "t2g6 eg0");
+    /// assert_eq!(field(PLATINUM, 2, Geometry::SquarePlanar), "eg4 a1g2 b2g2 b1g0");
+    /// ```
+    ///
+    /// Tetrahedral splitting is too small to overcome the pairing energy, so
+    /// tetrahedral complexes are always high spin.
+    pub fn new(ion: &Ion, geometry: Geometry, strength: FieldStrength) -> Result<Self> {
+        let table = ion.data().table_data;
+
+        if !(3..=12).contains(&table.xpos) || table.ypos > 7 {
+            return Err(Error::NotTransitionMetal(ion.symbol()));
+        }
+
+        // Only the (n - 1)d subshell below the valence shell of the period is
+        // split, any d subshell further in is part of the core
+        let d_electrons = ion
+            .electron_configuration()?
+            .electrons_in(table.period - 1, SubshellKind::D.letter());
+
+        Self::from_d_electrons(d_electrons, geometry, strength)
+    }
+
+    /// Crystal field of a metal with `d_electrons` d electrons
+    pub fn from_d_electrons(
+        d_electrons: u8,
+        geometry: Geometry,
+        strength: FieldStrength,
+    ) -> Result<Self> {
+        if d_electrons > 10 {
+            return Err(Error::TooManyElectrons(d_electrons));
+        }
+
+        let high_spin = strength == FieldStrength::Weak || geometry == Geometry::Tetrahedral;
+        let mut sets = geometry.orbital_sets().to_vec();
+        let mut remaining = d_electrons;
+
+        match high_spin {
+            // Every orbital is singly occupied before any is paired
+            true => {
+                for pass in [1, 2] {
+                    for set in &mut sets {
+                        let added = remaining.min(pass * set.orbitals - set.electrons);
+
+                        set.electrons += added;
+                        remaining -= added;
+                    }
+                }
+            }
+            // Each set is filled before the next is started
+            false => {
+                for set in &mut sets {
+                    set.electrons = remaining.min(2 * set.orbitals);
+                    remaining -= set.electrons;
+                }
+            }
+        }
+
+        Ok(Self {
+            geometry,
+            high_spin,
+            sets,
+        })
+    }
+
+    pub fn d_electrons(&self) -> u8 {
+        self.sets.iter().map(|set| set.electrons).sum()
+    }
+
+    pub fn unpaired_electrons(&self) -> u8 {
+        self.sets.iter().map(OrbitalSet::unpaired_electrons).sum()
+    }
+
+    /// Crystal field stabilisation energy in units of Δ, negative when the
+    /// complex is stabilised
+    pub fn cfse(&self) -> f64 {
+        self.sets
+            .iter()
+            .map(|set| set.electrons as f64 * set.energy)
+            .sum()
+    }
+
+    /// Number of electron pairs beyond those of the free ion, each costing
+    /// one pairing energy on top of the [`CrystalField::cfse`]
+    pub fn extra_pairs(&self) -> u8 {
+        let pairs = self.sets.iter().map(OrbitalSet::pairs).sum::<u8>();
+
+        pairs - self.d_electrons().saturating_sub(5)
+    }
+}
+
+impl fmt::Display for CrystalField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sets = self
+            .sets
+            .iter()
+            .map(|set| format!("{}{}", set.label, set.electrons))
+            .collect::<Vec<_>>();
+
+        f.write_str(&sets.join(" "))
+    }
+}
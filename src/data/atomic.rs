@@ -1,7 +1,12 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
 use proc_macro2::{Delimiter, Group, TokenStream};
 use quote::{quote, ToTokens, TokenStreamExt};
 
-#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+/// Equality, ordering and hashing only consider the atomic number, matching
+/// [`Element`](crate::element::Element), so sorting gives periodic order
+#[derive(Copy, Clone, Debug)]
 pub struct AtomicData {
     /// Number of protons
     pub atomic_number: u8,
@@ -11,6 +16,38 @@ pub struct AtomicData {
     pub atomic_mass: f64,
 }
 
+impl AtomicData {
+    const fn key(&self) -> u8 {
+        self.atomic_number
+    }
+}
+
+impl PartialEq for AtomicData {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for AtomicData {}
+
+impl PartialOrd for AtomicData {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AtomicData {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl Hash for AtomicData {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
 impl ToTokens for AtomicData {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let atomic_number = self.atomic_number;
@@ -0,0 +1,39 @@
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use quote::{quote, ToTokens, TokenStreamExt};
+
+use super::option_to_tokens;
+
+/// Information used when presenting an element to a user
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct DisplayData {
+    /// Description of what the element looks like
+    pub appearance: Option<&'static str>,
+    /// Colour of the element
+    pub color: Option<&'static str>,
+    /// CPK colour as a hex string without a leading `#`
+    pub cpk_hex: Option<&'static str>,
+    /// URL of an image of the element's emission spectrum
+    pub spectral_img: Option<&'static str>,
+}
+
+impl ToTokens for DisplayData {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let appearance = option_to_tokens(self.appearance);
+        let color = option_to_tokens(self.color);
+        let cpk_hex = option_to_tokens(self.cpk_hex);
+        let spectral_img = option_to_tokens(self.spectral_img);
+
+        let add_tokens = quote! {
+            chemistru_elements::data::display::DisplayData {
+                appearance: #appearance,
+                color: #color,
+                cpk_hex: #cpk_hex,
+                spectral_img: #spectral_img,
+            }
+        };
+
+        let group = TokenTree::Group(Group::new(Delimiter::None, add_tokens));
+
+        tokens.append(group);
+    }
+}
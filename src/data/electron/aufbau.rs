@@ -0,0 +1,91 @@
+use crate::error::{Error, Result};
+
+use super::configuration::{ElectronConfiguration, ENERGY_LEVELS};
+use super::orbital::{block_capacity, BLOCKS};
+
+/// Neutral atoms whose ground state differs from the Madelung filling order
+const ANOMALIES: [(u8, &str); 19] = [
+    (24, "[Ar] 3d5 4s1"),
+    (29, "[Ar] 3d10 4s1"),
+    (41, "[Kr] 4d4 5s1"),
+    (42, "[Kr] 4d5 5s1"),
+    (44, "[Kr] 4d7 5s1"),
+    (45, "[Kr] 4d8 5s1"),
+    (46, "[Kr] 4d10"),
+    (47, "[Kr] 4d10 5s1"),
+    (57, "[Xe] 5d1 6s2"),
+    (58, "[Xe] 4f1 5d1 6s2"),
+    (64, "[Xe] 4f7 5d1 6s2"),
+    (78, "[Xe] 4f14 5d9 6s1"),
+    (79, "[Xe] 4f14 5d10 6s1"),
+    (89, "[Rn] 6d1 7s2"),
+    (90, "[Rn] 6d2 7s2"),
+    (91, "[Rn] 5f2 6d1 7s2"),
+    (92, "[Rn] 5f3 6d1 7s2"),
+    (93, "[Rn] 5f4 6d1 7s2"),
+    (96, "[Rn] 5f7 6d1 7s2"),
+];
+
+/// Quantum number and block letter of every suborbital in Madelung order,
+/// increasing n + l with ties broken by lower n
+///
+/// Stops at 10p, as the next suborbital is 11s which cannot be represented
+pub(crate) fn madelung_order() -> impl Iterator<Item = (u8, char)> {
+    (1u8..=ENERGY_LEVELS as u8 + 1).flat_map(|sum| {
+        (0u8..BLOCKS.len() as u8)
+            .rev()
+            .filter(move |&l| sum > 2 * l && sum - l <= ENERGY_LEVELS as u8)
+            .map(move |l| (sum - l, BLOCKS[l as usize]))
+    })
+}
+
+impl ElectronConfiguration {
+    /// Fill suborbitals in Madelung (n + l) order
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// let iron = ElectronConfiguration::aufbau(26).unwrap();
+    ///
+    /// assert_eq!(iron.to_string(), "1s2 2s2 2p6 3s2 3p6 3d6 4s2");
+    /// ```
+    pub fn aufbau(electron_count: u8) -> Result<Self> {
+        let mut configuration = Self::new_empty();
+        let mut remaining = electron_count;
+
+        for (n, block) in madelung_order() {
+            if remaining == 0 {
+                break;
+            }
+
+            let electrons = remaining.min(block_capacity(block));
+
+            *configuration.electrons_in_mut(n, block) = electrons;
+            remaining -= electrons;
+        }
+
+        match remaining {
+            0 => Ok(configuration),
+            _ => Err(Error::TooManyElectrons(electron_count)),
+        }
+    }
+
+    /// Ground state of the neutral atom with atomic number `z`
+    ///
+    /// Follows the Madelung order except for known anomalies, such as
+    /// chromium and copper which promote an s electron into the d block.
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// let copper = ElectronConfiguration::ground_state(29).unwrap();
+    ///
+    /// assert_eq!(copper.to_string(), "1s2 2s2 2p6 3s2 3p6 3d10 4s1");
+    /// ```
+    pub fn ground_state(z: u8) -> Result<Self> {
+        match ANOMALIES.iter().find(|&&(anomaly, _)| anomaly == z) {
+            Some((_, configuration)) => configuration.parse(),
+            None => Self::aufbau(z),
+        }
+    }
+}
@@ -1,54 +1,366 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
 use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
 use quote::{quote, ToTokens, TokenStreamExt};
 
-use crate::suborbital;
+use crate::error::{Error, Result};
+
+use super::orbital::{EnergyLevel, SubOrbital, BLOCKS};
+use super::subshell::{Subshell, SubshellOrder};
+
+/// Noble gases usable as the core of shorthand notation, in increasing size
+const NOBLE_GAS_CORES: [(&str, &str); 7] = [
+    ("He", "1s2"),
+    ("Ne", "[He] 2s2 2p6"),
+    ("Ar", "[Ne] 3s2 3p6"),
+    ("Kr", "[Ar] 3d10 4s2 4p6"),
+    ("Xe", "[Kr] 4d10 5s2 5p6"),
+    ("Rn", "[Xe] 4f14 5d10 6s2 6p6"),
+    ("Og", "[Rn] 5f14 6d10 7s2 7p6"),
+];
 
-use super::orbital::{EnergyLevel, SubOrbital};
+pub(crate) const SUPERSCRIPT_DIGITS: [char; 10] =
+    ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
 
-/// Representation of electron configuration using StaticVec
-#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
-pub struct ElectronConfiguration([EnergyLevel; 8]);
+/// Number of energy levels in an [`ElectronConfiguration`], enough for the
+/// predicted configurations of elements up to 172
+pub const ENERGY_LEVELS: usize = 10;
+
+/// Representation of electron configuration as a fixed number of energy levels
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ElectronConfiguration([EnergyLevel; ENERGY_LEVELS]);
 
 impl ElectronConfiguration {
-    pub const fn new(levels: [EnergyLevel; 8]) -> Self {
-        Self(levels)
-    }
+    /// Configuration with the given electron count in each block of each
+    /// energy level, usable in constants
+    ///
+    /// This is the only public constructor, so the levels of a configuration
+    /// always have quantum numbers 1 to [`ENERGY_LEVELS`] in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than [`ENERGY_LEVELS`] levels are given, or if any level
+    /// is rejected by [`EnergyLevel::from_counts`].
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// const LITHIUM: ElectronConfiguration =
+    ///     ElectronConfiguration::from_counts([[2, 0, 0, 0, 0, 0], [1, 0, 0, 0, 0, 0]]);
+    ///
+    /// assert_eq!(LITHIUM.to_string(), "1s2 2s1");
+    /// ```
+    pub const fn from_counts<const N: usize>(counts: [[u8; 6]; N]) -> Self {
+        assert!(N <= ENERGY_LEVELS, "Too many energy levels");
 
-    pub fn new_empty() -> Self {
-        let mut n = 0u8..;
+        let mut levels = [EnergyLevel::from_counts(1, [0; 6]); ENERGY_LEVELS];
+        let mut idx = 0;
 
-        let levels = [(); 8].map(|_| {
-            let q = n.next().expect("Infallible");
+        while idx < ENERGY_LEVELS {
+            let level = match idx < N {
+                true => counts[idx],
+                false => [0; 6],
+            };
 
-            EnergyLevel {
-                s: suborbital!(s, q, 0),
-                p: suborbital!(p, q, 0),
-                d: suborbital!(d, q, 0),
-                f: suborbital!(f, q, 0),
-            }
-        });
+            levels[idx] = EnergyLevel::from_counts(idx as u8 + 1, level);
+            idx += 1;
+        }
 
         Self(levels)
     }
 
+    pub const fn new_empty() -> Self {
+        Self::from_counts::<0>([])
+    }
+
     pub fn shells(&self) -> Vec<EnergyLevel> {
         let n = self
             .0
             .iter()
-            .find(|&e| e.s.electrons() + e.p.electrons() + e.d.electrons() + e.f.electrons() == 0)
+            .find(|&e| e.counts().iter().all(|&c| c == 0))
             .map(|e| e.quantum_number() - 1)
-            .unwrap_or(8);
+            .unwrap_or(ENERGY_LEVELS as u8);
 
         self.0[0..(n as usize)].to_vec()
     }
+
+    /// Total number of electrons across all energy levels
+    pub fn electron_count(&self) -> u32 {
+        self.occupied().map(|(_, _, e)| e as u32).sum()
+    }
+
+    /// Display with the given options, the plain [`Display`](fmt::Display)
+    /// implementation uses the defaults
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// let sodium: ElectronConfiguration = "[Ne] 3s1".parse().unwrap();
+    ///
+    /// assert_eq!(sodium.to_string(), "1s2 2s2 2p6 3s1");
+    ///
+    /// let options = DisplayOptions {
+    ///     shorthand: true,
+    ///     superscript: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(sodium.display(options).to_string(), "[Ne] 3s¹");
+    ///
+    /// let iron = ElectronConfiguration::ground_state(26).unwrap();
+    ///
+    /// let options = DisplayOptions {
+    ///     shorthand: true,
+    ///     order: SubshellOrder::Energy,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(iron.display(options).to_string(), "[Ar] 4s2 3d6");
+    /// ```
+    pub fn display(&self, options: DisplayOptions) -> ConfigurationDisplay<'_> {
+        ConfigurationDisplay {
+            configuration: self,
+            options,
+        }
+    }
+
+    /// Set the electron count of the matching subshell
+    pub fn set_subshell(&mut self, subshell: Subshell) {
+        *self.electrons_in_mut(subshell.n(), subshell.block()) = subshell.electrons();
+    }
+
+    /// Quantum number, block letter and electron count of occupied suborbitals
+    pub(crate) fn occupied(&self) -> impl Iterator<Item = (u8, char, u8)> + '_ {
+        self.0.iter().flat_map(|level| {
+            let n = level.quantum_number();
+
+            BLOCKS
+                .into_iter()
+                .zip(level.counts())
+                .filter(|&(_, e)| e > 0)
+                .map(move |(block, e)| (n, block, e))
+        })
+    }
+
+    /// Largest noble gas core contained in the configuration, which has fewer
+    /// electrons than the whole configuration
+    pub(crate) fn noble_gas_core(&self) -> Option<(&'static str, Self)> {
+        let count = self.electron_count();
+
+        NOBLE_GAS_CORES
+            .iter()
+            .rev()
+            .map(|&(symbol, core)| (symbol, core.parse::<Self>().expect("Valid noble gas core")))
+            .find(|(_, core)| {
+                core.electron_count() < count
+                    && core
+                        .occupied()
+                        .all(|(n, block, e)| self.electrons_in(n, block) == e)
+            })
+    }
+
+    pub(crate) fn electrons_in(&self, n: u8, block: char) -> u8 {
+        let level = &self.0[n as usize - 1];
+
+        match block {
+            's' => level.s.electrons(),
+            'p' => level.p.electrons(),
+            'd' => level.d.electrons(),
+            'f' => level.f.electrons(),
+            'g' => level.g.electrons(),
+            'h' => level.h.electrons(),
+            _ => unreachable!("Invalid suborbital letter"),
+        }
+    }
+
+    pub(crate) fn electrons_in_mut(&mut self, n: u8, block: char) -> &mut u8 {
+        let level = &mut self.0[n as usize - 1];
+
+        match block {
+            's' => level.s.electrons_as_mut(),
+            'p' => level.p.electrons_as_mut(),
+            'd' => level.d.electrons_as_mut(),
+            'f' => level.f.electrons_as_mut(),
+            'g' => level.g.electrons_as_mut(),
+            'h' => level.h.electrons_as_mut(),
+            _ => unreachable!("Invalid suborbital letter"),
+        }
+    }
+}
+
+/// Options for displaying an [`ElectronConfiguration`]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DisplayOptions {
+    /// Replace the largest noble gas core with its symbol, e.g. `[Ne] 3s1`
+    pub shorthand: bool,
+    /// Write electron counts as Unicode superscripts, e.g. `1s²`
+    pub superscript: bool,
+    /// Order of the subshells, by shell unless set
+    pub order: SubshellOrder,
 }
 
+/// Displays an [`ElectronConfiguration`] with [`DisplayOptions`]
+pub struct ConfigurationDisplay<'a> {
+    configuration: &'a ElectronConfiguration,
+    options: DisplayOptions,
+}
+
+impl fmt::Display for ConfigurationDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+
+        let core = match self.options.shorthand {
+            true => self.configuration.noble_gas_core(),
+            false => None,
+        };
+
+        if let Some((symbol, _)) = core {
+            parts.push(format!("[{symbol}]"));
+        }
+
+        for subshell in self.configuration.iter_in(self.options.order) {
+            let (n, e) = (subshell.n(), subshell.electrons());
+            let block = subshell.block();
+
+            if let Some((_, core)) = &core {
+                if core.electrons_in(n, block) == e {
+                    continue;
+                }
+            }
+
+            let e = match self.options.superscript {
+                true => e
+                    .to_string()
+                    .chars()
+                    .map(|c| SUPERSCRIPT_DIGITS[c.to_digit(10).expect("Digit") as usize])
+                    .collect(),
+                false => e.to_string(),
+            };
+
+            parts.push(format!("{n}{block}{e}"));
+        }
+
+        f.write_str(&parts.join(" "))
+    }
+}
+
+impl fmt::Display for ElectronConfiguration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(DisplayOptions::default()).fmt(f)
+    }
+}
+
+impl FromStr for ElectronConfiguration {
+    type Err = Error;
+
+    /// Parse full notation such as `1s2 2s2 2p6 3s1` or noble gas shorthand
+    /// such as `[Ne] 3s1`, electron counts may be written as superscripts
+    ///
+    /// Each subshell may only be given once, including those of the noble
+    /// gas core, and the core must come first.
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// assert!("1s2 1s1".parse::<ElectronConfiguration>().is_err());
+    /// assert!("[Ne] 2p5".parse::<ElectronConfiguration>().is_err());
+    /// assert!("1s2 [He]".parse::<ElectronConfiguration>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        let mut configuration = Self::new_empty();
+        let mut seen = HashSet::new();
+
+        for (idx, token) in s.split_whitespace().enumerate() {
+            let token = match token.strip_prefix('[') {
+                Some(rest) => {
+                    let (symbol, rest) = rest
+                        .split_once(']')
+                        .ok_or_else(|| Error::InvalidSuborbital(token.to_string()))?;
+
+                    if idx > 0 {
+                        return Err(Error::MisplacedNobleGasCore(symbol.to_string()));
+                    }
+
+                    let core = NOBLE_GAS_CORES
+                        .iter()
+                        .find(|&&(noble_gas, _)| noble_gas == symbol)
+                        .ok_or_else(|| Error::UnknownNobleGasCore(symbol.to_string()))?
+                        .1
+                        .parse::<Self>()?;
+
+                    for (n, block, e) in core.occupied() {
+                        *configuration.electrons_in_mut(n, block) = e;
+                        seen.insert((n, block));
+                    }
+
+                    // Shorthand may be written without a space, e.g. `[He]2s1`
+                    match rest.is_empty() {
+                        true => continue,
+                        false => rest,
+                    }
+                }
+                None => token,
+            };
+
+            let token = token
+                .chars()
+                .map(|c| match SUPERSCRIPT_DIGITS.iter().position(|&d| d == c) {
+                    Some(digit) => char::from(b'0' + digit as u8),
+                    None => c,
+                })
+                .collect::<String>();
+
+            let subshell = token.parse::<Subshell>()?;
+
+            if !seen.insert((subshell.n(), subshell.block())) {
+                return Err(Error::DuplicateSuborbital(token));
+            }
+
+            configuration.set_subshell(subshell);
+        }
+
+        Ok(configuration)
+    }
+}
+
+/// Emits a call to [`ElectronConfiguration::from_counts`] with every level up
+/// to the last occupied one, including empty levels in between
+///
+/// ```
+/// use chemistru_elements::data::prelude::*;
+/// use quote::ToTokens;
+///
+/// let gapped: ElectronConfiguration = "1s2 3s1".parse().unwrap();
+/// let tokens = gapped.to_token_stream().to_string().replace(' ', "");
+///
+/// assert!(tokens.ends_with("from_counts([[2u8,0u8,0u8,0u8,0u8,0u8],[0u8,0u8,0u8,0u8,0u8,0u8],[1u8,0u8,0u8,0u8,0u8,0u8]])"));
+/// assert_eq!(
+///     gapped,
+///     ElectronConfiguration::from_counts([[2, 0, 0, 0, 0, 0], [0; 6], [1, 0, 0, 0, 0, 0]])
+/// );
+/// ```
 impl ToTokens for ElectronConfiguration {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let orbitals = self.0;
+        // Trailing empty energy levels are filled in by `from_counts`
+        let occupied = self
+            .0
+            .iter()
+            .rposition(|level| level.counts().iter().any(|&c| c > 0))
+            .map_or(0, |idx| idx + 1);
+
+        let levels = self.0[..occupied]
+            .iter()
+            .map(|level| {
+                let counts = level.counts();
+
+                quote!([#(#counts),*])
+            })
+            .collect::<Vec<_>>();
 
         let add_tokens = quote! {
-            chemistru_elements::data::electron::configuration::ElectronConfiguration::new([#(#orbitals),*])
+            chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([#(#levels),*])
         };
 
         tokens.append(TokenTree::Group(Group::new(Delimiter::None, add_tokens)));
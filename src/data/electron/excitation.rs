@@ -0,0 +1,137 @@
+use std::fmt;
+
+use crate::error::{Error, Result};
+
+use super::configuration::ElectronConfiguration;
+use super::orbital::BLOCKS;
+use super::subshell::Subshell;
+
+/// Change in the electron count of one subshell between two configurations
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SubshellDiff {
+    pub n: u8,
+    pub l: u8,
+    pub change: i8,
+}
+
+impl fmt::Display for SubshellDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match BLOCKS.get(self.l as usize) {
+            Some(block) => write!(f, "{}{block}{:+}", self.n, self.change),
+            None => write!(f, "{}({}){:+}", self.n, self.l, self.change),
+        }
+    }
+}
+
+impl ElectronConfiguration {
+    /// Add an electron to subshell `(n, l)`
+    ///
+    /// Fails if the subshell does not exist or is already full
+    pub fn add_electron(&mut self, n: u8, l: u8) -> Result<()> {
+        let subshell = self.subshell(n, l)?;
+
+        if subshell.is_full() {
+            return Err(Error::OverfilledSuborbital {
+                suborbital: subshell
+                    .with_electrons(subshell.electrons() + 1)
+                    .to_string(),
+                capacity: subshell.capacity(),
+            });
+        }
+
+        *self.electrons_in_mut(n, subshell.block()) += 1;
+
+        Ok(())
+    }
+
+    /// Remove an electron from subshell `(n, l)`
+    ///
+    /// Fails if the subshell does not exist or is empty
+    pub fn remove_electron(&mut self, n: u8, l: u8) -> Result<()> {
+        let subshell = self.subshell(n, l)?;
+
+        if subshell.is_empty() {
+            return Err(Error::EmptySuborbital(format!("{n}{}", subshell.block())));
+        }
+
+        *self.electrons_in_mut(n, subshell.block()) -= 1;
+
+        Ok(())
+    }
+
+    /// Move an electron from subshell `from` to subshell `to`, both given as
+    /// `(n, l)`
+    ///
+    /// The configuration is unchanged if either step fails.
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// let mut carbon = ElectronConfiguration::ground_state(6).unwrap();
+    /// carbon.promote((2, 0), (2, 1)).unwrap();
+    ///
+    /// assert_eq!(carbon.to_string(), "1s2 2s1 2p3");
+    /// assert!(!carbon.is_ground_state());
+    /// ```
+    pub fn promote(&mut self, from: (u8, u8), to: (u8, u8)) -> Result<()> {
+        let mut promoted = *self;
+
+        promoted.remove_electron(from.0, from.1)?;
+        promoted.add_electron(to.0, to.1)?;
+
+        *self = promoted;
+
+        Ok(())
+    }
+
+    /// Subshells whose electron count differs from `other`, with the change
+    /// needed to get from `self` to `other`
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// let ground = ElectronConfiguration::ground_state(6).unwrap();
+    /// let excited: ElectronConfiguration = "1s2 2s1 2p3".parse().unwrap();
+    ///
+    /// let diff = ground.diff(&excited).iter().map(ToString::to_string).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(diff, ["2s-1", "2p+1"]);
+    /// ```
+    pub fn diff(&self, other: &Self) -> Vec<SubshellDiff> {
+        let mut keys = self
+            .iter_by_shell()
+            .chain(other.iter_by_shell())
+            .map(|s| (s.n(), s.l(), s.block()))
+            .collect::<Vec<_>>();
+
+        keys.sort_unstable();
+        keys.dedup();
+
+        keys.into_iter()
+            .filter_map(|(n, l, block)| {
+                let change = other.electrons_in(n, block) as i8 - self.electrons_in(n, block) as i8;
+
+                (change != 0).then_some(SubshellDiff { n, l, change })
+            })
+            .collect()
+    }
+
+    /// Whether the configuration is the ground state of the neutral atom with
+    /// the same number of electrons
+    ///
+    /// Ions are compared against the isoelectronic atom, so iron(III) as
+    /// `[Ar] 3d5` is not considered a ground state.
+    pub fn is_ground_state(&self) -> bool {
+        u8::try_from(self.electron_count())
+            .ok()
+            .and_then(|count| Self::ground_state(count).ok())
+            .is_some_and(|ground| ground == *self)
+    }
+
+    /// Subshell `(n, l)` with its current electron count
+    fn subshell(&self, n: u8, l: u8) -> Result<Subshell> {
+        let subshell = Subshell::new(n, l, 0)?;
+
+        Ok(subshell.with_electrons(self.electrons_in(n, subshell.block())))
+    }
+}
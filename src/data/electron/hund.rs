@@ -0,0 +1,41 @@
+use super::configuration::ElectronConfiguration;
+use super::orbital::{block_boxes, OrbitalBox};
+
+impl ElectronConfiguration {
+    /// Number of unpaired electrons across all suborbitals
+    pub fn unpaired_electrons(&self) -> u8 {
+        self.occupied()
+            .flat_map(|(_, block, e)| block_boxes(block, e))
+            .filter(OrbitalBox::is_unpaired)
+            .count() as u8
+    }
+
+    /// Whether the configuration has unpaired electrons, and so is attracted
+    /// by a magnetic field
+    pub fn is_paramagnetic(&self) -> bool {
+        self.unpaired_electrons() > 0
+    }
+
+    /// Orbital box diagram with a line for each occupied suborbital
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// let nitrogen: ElectronConfiguration = "1s2 2s2 2p3".parse().unwrap();
+    ///
+    /// assert_eq!(nitrogen.box_diagram(), "1s [↑↓]\n2s [↑↓]\n2p [↑ ][↑ ][↑ ]");
+    /// ```
+    pub fn box_diagram(&self) -> String {
+        self.occupied()
+            .map(|(n, block, e)| {
+                let boxes = block_boxes(block, e)
+                    .iter()
+                    .map(OrbitalBox::to_string)
+                    .collect::<String>();
+
+                format!("{n}{block} {boxes}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,69 @@
+use crate::error::{Error, Result};
+
+use super::aufbau::madelung_order;
+use super::configuration::{ElectronConfiguration, ENERGY_LEVELS};
+use super::orbital::{block_angular_momentum, block_capacity};
+
+impl ElectronConfiguration {
+    /// Configuration of the ion with the given charge
+    ///
+    /// Cations lose electrons from outside the noble gas core first, taking
+    /// them from the highest energy level, and the highest block within it.
+    /// So iron(III) is `[Ar] 3d5` rather than `[Ar] 3d3 4s2`, and
+    /// praseodymium(III) keeps its 5p electrons to be `[Xe] 4f2`. Anions gain
+    /// electrons in Madelung order.
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// let iron = ElectronConfiguration::ground_state(26).unwrap();
+    ///
+    /// assert_eq!(iron.ionised(3).unwrap().to_string(), "1s2 2s2 2p6 3s2 3p6 3d5");
+    /// ```
+    pub fn ionised(&self, charge: i8) -> Result<Self> {
+        let mut configuration = *self;
+
+        if charge as i32 > self.electron_count() as i32 {
+            return Err(Error::InvalidCharge(charge));
+        }
+
+        let core = self.noble_gas_core().map(|(_, core)| core);
+
+        for _ in 0..charge.max(0) {
+            let (n, block, _) = configuration
+                .occupied()
+                .max_by_key(|&(n, block, e)| {
+                    let outside_core = match core {
+                        Some(core) => e > core.electrons_in(n, block),
+                        None => true,
+                    };
+
+                    (outside_core, n, block_angular_momentum(block))
+                })
+                .expect("Configuration has electrons left");
+
+            *configuration.electrons_in_mut(n, block) -= 1;
+        }
+
+        for _ in 0..charge.min(0).unsigned_abs() {
+            let (n, block) = madelung_order()
+                .find(|&(n, block)| configuration.electrons_in(n, block) < block_capacity(block))
+                .ok_or(Error::InvalidCharge(charge))?;
+
+            *configuration.electrons_in_mut(n, block) += 1;
+        }
+
+        Ok(configuration)
+    }
+
+    /// Number of electrons in each energy level
+    pub fn shell_counts(&self) -> [u8; ENERGY_LEVELS] {
+        let mut shells = [0u8; ENERGY_LEVELS];
+
+        for (n, _, e) in self.occupied() {
+            shells[n as usize - 1] += e;
+        }
+
+        shells
+    }
+}
@@ -1,5 +1,14 @@
+pub mod aufbau;
 pub mod configuration;
+pub mod excitation;
+pub mod hund;
+pub mod ionisation;
 pub mod orbital;
+pub mod quantum;
+pub mod slater;
+pub mod subshell;
+pub mod term;
+pub mod valence;
 
 use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
 use quote::{quote, ToTokens, TokenStreamExt};
@@ -8,9 +17,13 @@ use configuration::ElectronConfiguration;
 
 #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
 pub struct ElectronData {
-    pub shells: [u8; 8],
-    pub ionisation_energies: [f64; 30],
+    /// Number of electrons in each shell, without trailing empty shells
+    pub shells: &'static [u8],
+    /// Successive ionisation energies in kJ/mol, as many as are known
+    pub ionisation_energies: &'static [f64],
     pub electron_configuration: ElectronConfiguration,
+    /// Electron configuration in noble gas shorthand, e.g. `[Ar] 3d6 4s2`
+    pub electron_configuration_semantic: &'static str,
     pub electron_affinity: Option<f64>,
     pub electronegativity: Option<f64>,
 }
@@ -18,9 +31,8 @@ pub struct ElectronData {
 impl ToTokens for ElectronData {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let electron_configuration = &self.electron_configuration;
-        let ionisation_energies = self.ionisation_energies;
-
-        let ionisation_energies = slice_to_tokens(ionisation_energies);
+        let electron_configuration_semantic = self.electron_configuration_semantic;
+        let ionisation_energies = slice_to_tokens(self.ionisation_energies);
         let shells = slice_to_tokens(self.shells);
         let electron_affinity = match self.electron_affinity {
             Some(v) => quote!(Some(#v)),
@@ -35,6 +47,7 @@ impl ToTokens for ElectronData {
         let add_tokens = quote! {
             chemistru_elements::data::electron::ElectronData {
                 electron_configuration: #electron_configuration,
+                electron_configuration_semantic: #electron_configuration_semantic,
                 ionisation_energies: #ionisation_energies,
                 shells: #shells,
                 electron_affinity: #electron_affinity,
@@ -48,8 +61,6 @@ impl ToTokens for ElectronData {
     }
 }
 
-fn slice_to_tokens<T: ToTokens, const N: usize>(s: [T; N]) -> TokenStream {
-    let item = s.iter();
-
-    quote!([#(#item),*])
+fn slice_to_tokens<T: ToTokens>(s: &[T]) -> TokenStream {
+    quote!(&[#(#s),*])
 }
@@ -3,13 +3,24 @@ use std::ops::{Range, RangeInclusive};
 use proc_macro2::{Delimiter, Group, TokenTree};
 use quote::{quote, ToTokens, TokenStreamExt};
 
-/// An electron orbital, containing an S, P, D, and F block
+use crate::error::Error;
+
+/// Block letters indexed by angular momentum
+pub const BLOCKS: [char; 6] = ['s', 'p', 'd', 'f', 'g', 'h'];
+
+/// An electron orbital, containing an S, P, D, F, G and H block
+///
+/// The G and H blocks are only occupied in predicted configurations of
+/// superheavy elements beyond oganesson. Levels are only built through
+/// [`EnergyLevel::from_counts`], so every block shares the same quantum number.
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct EnergyLevel {
-    pub s: SOrbital,
-    pub p: POrbital,
-    pub d: DOrbital,
-    pub f: FOrbital,
+    pub(crate) s: SOrbital,
+    pub(crate) p: POrbital,
+    pub(crate) d: DOrbital,
+    pub(crate) f: FOrbital,
+    pub(crate) g: GOrbital,
+    pub(crate) h: HOrbital,
 }
 
 /// Suborbital containing up to 2 electrons
@@ -36,22 +47,110 @@ pub struct DOrbital(u8, u8);
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct FOrbital(u8, u8);
 
+/// Suborbital containing up to 18 electrons
+///
+/// G-block elements are predicted to start at element 121
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GOrbital(u8, u8);
+
+/// Suborbital containing up to 22 electrons
+///
+/// No element is predicted to fill the H-block below element 172
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HOrbital(u8, u8);
+
 /// Trait containing methods relating to fullness, capacity and orbital number
 pub trait SubOrbital {
     /// Number of energy level
     fn quantum_number(&self) -> u8;
     /// Indicates which sublevel the suborbital is
     fn angular_momentum(&self) -> u8;
-    /// The orbital the last electron is contained in
-    fn magnetic_quantum_number(&self) -> i8;
-    /// Spin of last electron in suborbital
-    fn magnetic_spin_number(&self) -> f64;
     /// Number of electrons in suborbital
     fn electrons(&self) -> u8;
     /// Maximum number of electrons in suborbital
     fn capacity(&self) -> u8;
     /// Mutable reference to electron count
     fn electrons_as_mut(&mut self) -> &mut u8;
+
+    /// The orbital the last electron is contained in
+    fn magnetic_quantum_number(&self) -> i8 {
+        let orbitals = self.capacity() / 2;
+
+        // Orbitals are filled from +l down to -l, first singly then paired
+        let idx = match self.electrons() <= orbitals {
+            true => self.electrons().saturating_sub(1),
+            false => self.electrons() - orbitals - 1,
+        };
+
+        self.angular_momentum() as i8 - idx as i8
+    }
+
+    /// Spin of last electron in suborbital
+    fn magnetic_spin_number(&self) -> f64 {
+        match self.electrons().cmp(&(self.capacity() / 2)) {
+            std::cmp::Ordering::Less | std::cmp::Ordering::Equal => 0.5,
+            std::cmp::Ordering::Greater => -0.5,
+        }
+    }
+
+    /// Number of electrons without a partner of opposite spin, following
+    /// Hund's rule of maximum multiplicity
+    fn unpaired_electrons(&self) -> u8 {
+        let orbitals = self.capacity() / 2;
+
+        match self.electrons() <= orbitals {
+            true => self.electrons(),
+            false => self.capacity() - self.electrons(),
+        }
+    }
+}
+
+/// Single orbital of a suborbital, holding up to two electrons of opposite spin
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct OrbitalBox {
+    pub magnetic_quantum_number: i8,
+    pub spin_up: bool,
+    pub spin_down: bool,
+}
+
+impl OrbitalBox {
+    pub const fn electrons(&self) -> u8 {
+        self.spin_up as u8 + self.spin_down as u8
+    }
+
+    pub const fn is_unpaired(&self) -> bool {
+        self.electrons() == 1
+    }
+}
+
+impl std::fmt::Display for OrbitalBox {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let up = match self.spin_up {
+            true => '↑',
+            false => ' ',
+        };
+
+        let down = match self.spin_down {
+            true => '↓',
+            false => ' ',
+        };
+
+        write!(f, "[{up}{down}]")
+    }
+}
+
+/// Distribute electrons over the `N` orbitals of a suborbital by Hund's rule
+///
+/// Orbitals are ordered from m_l = +l down to m_l = -l. Each is singly filled
+/// with spin up electrons before any are paired.
+pub(crate) fn hund_boxes<const N: usize>(electrons: u8) -> [OrbitalBox; N] {
+    let l = (N as i8 - 1) / 2;
+
+    std::array::from_fn(|idx| OrbitalBox {
+        magnetic_quantum_number: l - idx as i8,
+        spin_up: (electrons as usize) > idx,
+        spin_down: (electrons as usize) > N + idx,
+    })
 }
 
 /// Supertrait of SubOrbital with associated capacity constant
@@ -64,17 +163,81 @@ pub trait CapSubOrbital: SubOrbital {
 }
 
 impl EnergyLevel {
+    /// Quantum number shared by all suborbitals of the energy level
+    ///
+    /// # Panics
+    ///
+    /// Panics if the suborbitals disagree, which cannot happen for levels built
+    /// by [`EnergyLevel::from_counts`], see [`EnergyLevel::try_quantum_number`]
     pub const fn quantum_number(&self) -> u8 {
-        let s = self.s.0;
-        let p = self.p.0;
-        let d = self.d.0;
-        let f = self.f.0;
+        assert!(
+            self.has_consistent_quantum_number(),
+            "Suborbitals of energy level have different quantum numbers"
+        );
+
+        self.s.0
+    }
+
+    /// Quantum number shared by all suborbitals of the energy level
+    pub fn try_quantum_number(&self) -> Result<u8, Error> {
+        match self.has_consistent_quantum_number() {
+            true => Ok(self.s.0),
+            false => Err(Error::MismatchedQuantumNumbers([
+                self.s.0, self.p.0, self.d.0, self.f.0, self.g.0, self.h.0,
+            ])),
+        }
+    }
 
-        assert!(s == p);
-        assert!(s == d);
-        assert!(s == f);
+    /// Energy level with the given electron count in each block, in the order
+    /// of [`BLOCKS`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if a count exceeds the capacity of its block, or if a block that
+    /// does not exist in the energy level (`l >= n`) holds any electrons.
+    ///
+    /// ```should_panic
+    /// use chemistru_elements::data::electron::orbital::EnergyLevel;
+    ///
+    /// // There is no 1p suborbital
+    /// EnergyLevel::from_counts(1, [2, 1, 0, 0, 0, 0]);
+    /// ```
+    pub const fn from_counts(n: u8, counts: [u8; 6]) -> Self {
+        let mut l = 0;
+
+        while l < BLOCKS.len() {
+            assert!(
+                counts[l] <= block_capacity(BLOCKS[l]),
+                "Suborbital overfilled"
+            );
+            assert!(
+                counts[l] == 0 || l < n as usize,
+                "Suborbital does not exist in energy level"
+            );
+            l += 1;
+        }
+
+        let [s, p, d, f, g, h] = counts;
+
+        Self {
+            s: SOrbital::new(n, s),
+            p: POrbital::new(n, p),
+            d: DOrbital::new(n, d),
+            f: FOrbital::new(n, f),
+            g: GOrbital::new(n, g),
+            h: HOrbital::new(n, h),
+        }
+    }
 
-        s
+    /// Electron count of each block, in the order of [`BLOCKS`]
+    pub const fn counts(&self) -> [u8; 6] {
+        [self.s.1, self.p.1, self.d.1, self.f.1, self.g.1, self.h.1]
+    }
+
+    const fn has_consistent_quantum_number(&self) -> bool {
+        let s = self.s.0;
+
+        s == self.p.0 && s == self.d.0 && s == self.f.0 && s == self.g.0 && s == self.h.0
     }
 
     pub const fn possible_angular_momenta(&self) -> Range<u8> {
@@ -96,46 +259,22 @@ macro_rules! impl_suborbital_block {
 
                 fn capacity(&self) -> u8 { $cap }
 
-                fn angular_momentum(&self) -> u8 { match $block_letter {
-                    's' => 0,
-                    'p' => 1,
-                    'd' => 2,
-                    'f' => 3,
-                    _ => panic!("Invalid suborbital letter")
-                }}
-
-                fn magnetic_quantum_number(&self) -> i8 {
-                    (self.electrons() % (self.capacity() / 2)) as i8 - self.angular_momentum() as i8
-                }
-
-                fn magnetic_spin_number(&self) -> f64 {
-                    match self.electrons().cmp(&(self.capacity() / 2)) {
-                        std::cmp::Ordering::Less | std::cmp::Ordering::Equal => 0.5,
-                        std::cmp::Ordering::Greater => -0.5,
-                    }
-                }
+                fn angular_momentum(&self) -> u8 { block_angular_momentum($block_letter) }
 
                 fn electrons_as_mut(&mut self) -> &mut u8 { &mut self.1 }
             }
 
             impl CapSubOrbital for $t {
                 const CAPACITY: u8 = $cap;
-                const ANGULAR_MOMENTUM: u8 = match $block_letter {
-                    's' => 0,
-                    'p' => 1,
-                    'd' => 2,
-                    'f' => 3,
-                    _ => panic!("Invalid suborbital letter")
-                };
+                const ANGULAR_MOMENTUM: u8 = block_angular_momentum($block_letter);
             }
 
-            impl ToString for $t {
-                fn to_string(&self) -> String {
+            impl std::fmt::Display for $t {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                     match self.electrons() {
-                        0 => String::new(),
-                        e => format!("{}{}{}", self.quantum_number(), $block_letter, e),
+                        0 => Ok(()),
+                        e => write!(f, "{}{}{}", self.quantum_number(), $block_letter, e),
                     }
-
                 }
             }
 
@@ -151,6 +290,11 @@ macro_rules! impl_suborbital_block {
                     self.1 == 0
                 }
 
+                /// Occupancy of each orbital, filled by Hund's rule
+                pub fn boxes(&self) -> [OrbitalBox; $cap / 2] {
+                    hund_boxes(self.1)
+                }
+
             }
         )*
     };
@@ -161,29 +305,54 @@ impl_suborbital_block! {
     POrbital, 6,  'p',
     DOrbital, 10, 'd',
     FOrbital, 14, 'f',
+    GOrbital, 18, 'g',
+    HOrbital, 22, 'h',
 }
 
 impl ToTokens for EnergyLevel {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let n = self.quantum_number();
-        let s = self.s.1;
-        let p = self.p.1;
-        let d = self.d.1;
-        let f = self.f.1;
+        let counts = self.counts();
 
         let add_tokens: proc_macro2::TokenStream = quote! {
-            chemistru_elements::data::electron::orbital::EnergyLevel {
-                s: chemistru_elements::data::electron::orbital::SOrbital::new(#n, #s),
-                p: chemistru_elements::data::electron::orbital::POrbital::new(#n, #p),
-                d: chemistru_elements::data::electron::orbital::DOrbital::new(#n, #d),
-                f: chemistru_elements::data::electron::orbital::FOrbital::new(#n, #f),
-            }
+            chemistru_elements::data::electron::orbital::EnergyLevel::from_counts(#n, [#(#counts),*])
         };
 
         tokens.append(TokenTree::Group(Group::new(Delimiter::None, add_tokens)));
     }
 }
 
+/// Angular momentum of the block with the given letter
+pub(crate) const fn block_angular_momentum(block: char) -> u8 {
+    match block {
+        's' => 0,
+        'p' => 1,
+        'd' => 2,
+        'f' => 3,
+        'g' => 4,
+        'h' => 5,
+        _ => panic!("Invalid suborbital letter"),
+    }
+}
+
+/// Capacity of the block with the given letter
+pub(crate) const fn block_capacity(block: char) -> u8 {
+    4 * block_angular_momentum(block) + 2
+}
+
+/// Occupancy of each orbital of the block with the given letter, filled by
+/// Hund's rule
+pub(crate) fn block_boxes(block: char, electrons: u8) -> Vec<OrbitalBox> {
+    match block {
+        's' => hund_boxes::<1>(electrons).to_vec(),
+        'p' => hund_boxes::<3>(electrons).to_vec(),
+        'd' => hund_boxes::<5>(electrons).to_vec(),
+        'f' => hund_boxes::<7>(electrons).to_vec(),
+        'g' => hund_boxes::<9>(electrons).to_vec(),
+        _ => hund_boxes::<11>(electrons).to_vec(),
+    }
+}
+
 #[macro_export]
 macro_rules! suborbital {
     (s, $number:expr, $fullness:expr) => {
@@ -198,18 +367,21 @@ macro_rules! suborbital {
     (f, $number:expr, $fullness:expr) => {
         $crate::data::electron::orbital::FOrbital::new($number, $fullness)
     };
+    (g, $number:expr, $fullness:expr) => {
+        $crate::data::electron::orbital::GOrbital::new($number, $fullness)
+    };
+    (h, $number:expr, $fullness:expr) => {
+        $crate::data::electron::orbital::HOrbital::new($number, $fullness)
+    };
 }
 
-impl ToString for EnergyLevel {
-    fn to_string(&self) -> String {
-        format!(
-            "{} {} {} {}",
-            self.s.to_string(),
-            self.p.to_string(),
-            self.d.to_string(),
-            self.f.to_string()
-        )
-        .trim()
-        .to_string()
+impl std::fmt::Display for EnergyLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!(
+            "{} {} {} {} {} {}",
+            self.s, self.p, self.d, self.f, self.g, self.h
+        );
+
+        f.write_str(s.split_whitespace().collect::<Vec<_>>().join(" ").as_str())
     }
 }
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::error::{Error, Result};
+
+use super::configuration::ElectronConfiguration;
+
+/// Full set of quantum numbers describing a single electron
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct QuantumNumbers {
+    /// Principal quantum number
+    pub n: u8,
+    /// Angular momentum quantum number
+    pub l: u8,
+    /// Magnetic quantum number
+    pub m_l: i8,
+    /// Spin quantum number, ±½
+    pub m_s: f64,
+}
+
+impl QuantumNumbers {
+    /// Whether the quantum numbers describe an allowed state, with
+    /// `0 <= l < n`, `|m_l| <= l` and `m_s = ±½`
+    pub fn is_valid(&self) -> bool {
+        self.n > 0
+            && self.l < self.n
+            && self.m_l.unsigned_abs() <= self.l
+            && (self.m_s == 0.5 || self.m_s == -0.5)
+    }
+
+    /// Quantum numbers with the spin reduced to its sign, for comparing states
+    fn key(&self) -> (u8, u8, i8, bool) {
+        (self.n, self.l, self.m_l, self.m_s > 0.0)
+    }
+}
+
+impl fmt::Display for QuantumNumbers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let spin = match self.m_s > 0.0 {
+            true => "+½",
+            false => "-½",
+        };
+
+        write!(
+            f,
+            "(n = {}, l = {}, m_l = {}, m_s = {spin})",
+            self.n, self.l, self.m_l
+        )
+    }
+}
+
+/// Check that every set of quantum numbers is valid and that no two are equal,
+/// as required by the Pauli exclusion principle
+///
+/// ```
+/// use chemistru_elements::data::electron::quantum::{check_pauli, QuantumNumbers};
+///
+/// let electron = QuantumNumbers { n: 1, l: 0, m_l: 0, m_s: 0.5 };
+///
+/// assert!(check_pauli([electron]).is_ok());
+/// assert!(check_pauli([electron, electron]).is_err());
+/// ```
+pub fn check_pauli(electrons: impl IntoIterator<Item = QuantumNumbers>) -> Result<()> {
+    let mut seen = HashSet::new();
+
+    for electron in electrons {
+        if !electron.is_valid() {
+            return Err(Error::InvalidQuantumNumbers(electron));
+        }
+
+        if !seen.insert(electron.key()) {
+            return Err(Error::PauliExclusion(electron));
+        }
+    }
+
+    Ok(())
+}
+
+impl ElectronConfiguration {
+    /// Quantum numbers of every electron in fill order
+    ///
+    /// Suborbitals are taken in Madelung order, and within each suborbital
+    /// electrons fill orbitals from m_l = +l to -l with spin up, then pair
+    /// with spin down, following Hund's rule.
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// let nitrogen = ElectronConfiguration::ground_state(7).unwrap();
+    /// let last = nitrogen.electrons().last().unwrap();
+    ///
+    /// assert_eq!(last, QuantumNumbers { n: 2, l: 1, m_l: -1, m_s: 0.5 });
+    /// ```
+    pub fn electrons(&self) -> impl Iterator<Item = QuantumNumbers> {
+        self.iter_by_energy().flat_map(|subshell| {
+            let (n, l, electrons) = (subshell.n(), subshell.l(), subshell.electrons());
+            let orbitals = subshell.capacity() / 2;
+
+            (0..electrons).map(move |idx| {
+                let (m_l, m_s) = match idx < orbitals {
+                    true => (l as i8 - idx as i8, 0.5),
+                    false => (l as i8 - (idx - orbitals) as i8, -0.5),
+                };
+
+                QuantumNumbers { n, l, m_l, m_s }
+            })
+        })
+    }
+
+    /// Check the electrons of the configuration against the Pauli exclusion
+    /// principle, see [`check_pauli`]
+    pub fn check_pauli(&self) -> Result<()> {
+        check_pauli(self.electrons())
+    }
+}
@@ -0,0 +1,72 @@
+use crate::error::{Error, Result};
+
+use super::configuration::{ElectronConfiguration, ENERGY_LEVELS};
+use super::orbital::{block_angular_momentum, BLOCKS};
+
+/// Slater group of a suborbital, s and p share a group while d, f, g and h
+/// each have their own. Groups are ordered as `[1s] [2s 2p] [3s 3p] [3d] [4s 4p]
+/// [4d] [4f] ...`
+fn group(n: u8, l: u8) -> (u8, u8) {
+    (n, l.saturating_sub(1))
+}
+
+impl ElectronConfiguration {
+    /// Effective nuclear charge felt by an electron in suborbital `(n, l)` of
+    /// an atom with atomic number `z`, estimated by Slater's rules
+    ///
+    /// Each other electron shields the nucleus by
+    ///
+    /// - 0.35 in the same group, or 0.30 within 1s
+    /// - 0.85 one shell lower for s and p electrons, 1.00 further in
+    /// - 1.00 in any lower group for d, f, g and h electrons
+    ///
+    /// Electrons in higher groups do not shield. If the suborbital is empty
+    /// the charge is that felt by an added electron.
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// let zinc = ElectronConfiguration::ground_state(30).unwrap();
+    ///
+    /// assert!((zinc.effective_nuclear_charge(30, 4, 0).unwrap() - 4.35).abs() < 1e-9);
+    /// assert!((zinc.effective_nuclear_charge(30, 3, 2).unwrap() - 8.85).abs() < 1e-9);
+    /// ```
+    pub fn effective_nuclear_charge(&self, z: u8, n: u8, l: u8) -> Result<f64> {
+        if !(1..=ENERGY_LEVELS as u8).contains(&n) {
+            return Err(Error::InvalidQuantumNumber(n));
+        }
+
+        if l >= n || l as usize >= BLOCKS.len() {
+            return Err(Error::InvalidAngularMomentum(l));
+        }
+
+        let target = group(n, l);
+
+        let shielding = self
+            .occupied()
+            .map(|(other_n, block, e)| {
+                let other_l = block_angular_momentum(block);
+                let other = group(other_n, other_l);
+
+                // The electron does not shield itself
+                let e = match (other_n, other_l) == (n, l) {
+                    true => e - 1,
+                    false => e,
+                };
+
+                let factor = match other.cmp(&target) {
+                    std::cmp::Ordering::Greater => 0.0,
+                    std::cmp::Ordering::Equal if n == 1 => 0.30,
+                    std::cmp::Ordering::Equal => 0.35,
+                    std::cmp::Ordering::Less if l >= 2 => 1.00,
+                    std::cmp::Ordering::Less if other_n + 1 == n => 0.85,
+                    std::cmp::Ordering::Less => 1.00,
+                };
+
+                e as f64 * factor
+            })
+            .sum::<f64>();
+
+        Ok(z as f64 - shielding)
+    }
+}
@@ -0,0 +1,268 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+use super::configuration::{ElectronConfiguration, ENERGY_LEVELS};
+use super::orbital::{
+    block_angular_momentum, block_boxes, block_capacity, DOrbital, FOrbital, GOrbital, HOrbital,
+    OrbitalBox, POrbital, SOrbital, SubOrbital, BLOCKS,
+};
+
+/// Suborbital of any block, identified by its quantum numbers
+///
+/// The fields are only set through [`Subshell::new`] and the conversions from
+/// the suborbitals of each block, so the angular momentum always names a
+/// block.
+///
+/// Ordered by energy level, then by block, then by electron count, so a
+/// sorted list of subshells is in shell order.
+///
+/// ```
+/// use chemistru_elements::data::prelude::*;
+/// use chemistru_elements::suborbital;
+///
+/// let subshell: Subshell = "3d6".parse().unwrap();
+///
+/// assert_eq!(subshell.block(), 'd');
+/// assert_eq!(subshell.capacity(), 10);
+/// assert_eq!(Subshell::from(suborbital!(d, 3, 6)), subshell);
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Subshell {
+    n: u8,
+    l: u8,
+    electrons: u8,
+}
+
+impl Subshell {
+    /// Subshell with principal quantum number `n` and angular momentum `l`
+    pub fn new(n: u8, l: u8, electrons: u8) -> Result<Self> {
+        if !(1..=ENERGY_LEVELS as u8).contains(&n) {
+            return Err(Error::InvalidQuantumNumber(n));
+        }
+
+        if l >= n || l as usize >= BLOCKS.len() {
+            return Err(Error::InvalidAngularMomentum(l));
+        }
+
+        let subshell = Self { n, l, electrons };
+
+        match electrons > subshell.capacity() {
+            true => Err(Error::OverfilledSuborbital {
+                suborbital: subshell.to_string(),
+                capacity: subshell.capacity(),
+            }),
+            false => Ok(subshell),
+        }
+    }
+
+    /// Principal quantum number
+    pub const fn n(&self) -> u8 {
+        self.n
+    }
+
+    /// Angular momentum quantum number
+    pub const fn l(&self) -> u8 {
+        self.l
+    }
+
+    pub const fn electrons(&self) -> u8 {
+        self.electrons
+    }
+
+    /// Same subshell holding a different number of electrons, which may
+    /// exceed its capacity when describing an overfilled subshell in an error
+    pub(crate) const fn with_electrons(self, electrons: u8) -> Self {
+        Self { electrons, ..self }
+    }
+
+    /// Letter of the block, e.g. `d`
+    pub const fn block(&self) -> char {
+        BLOCKS[self.l as usize]
+    }
+
+    pub const fn kind(&self) -> SubshellKind {
+        SubshellKind::ALL[self.l as usize]
+    }
+
+    pub const fn capacity(&self) -> u8 {
+        block_capacity(self.block())
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.electrons == 0
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.electrons == self.capacity()
+    }
+
+    /// Occupancy of each orbital, filled by Hund's rule
+    pub fn boxes(&self) -> Vec<OrbitalBox> {
+        block_boxes(self.block(), self.electrons)
+    }
+}
+
+impl SubOrbital for Subshell {
+    fn quantum_number(&self) -> u8 {
+        self.n
+    }
+
+    fn angular_momentum(&self) -> u8 {
+        self.l
+    }
+
+    fn electrons(&self) -> u8 {
+        self.electrons
+    }
+
+    fn capacity(&self) -> u8 {
+        Subshell::capacity(self)
+    }
+
+    fn electrons_as_mut(&mut self) -> &mut u8 {
+        &mut self.electrons
+    }
+}
+
+impl fmt::Display for Subshell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.n, self.block(), self.electrons)
+    }
+}
+
+impl FromStr for Subshell {
+    type Err = Error;
+
+    /// Parse a subshell in the form `3d6`
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::InvalidSuborbital(s.to_string());
+
+        let letter_idx = s.find(|c: char| c.is_alphabetic()).ok_or_else(invalid)?;
+
+        let (number, rest) = s.split_at(letter_idx);
+        let mut rest = rest.chars();
+        let letter = rest.next().ok_or_else(invalid)?;
+
+        let n = number.parse::<u8>().map_err(|_| invalid())?;
+        let electrons = rest.as_str().parse::<u8>().map_err(|_| invalid())?;
+        let l = BLOCKS
+            .iter()
+            .position(|&b| b == letter)
+            .ok_or_else(invalid)? as u8;
+
+        match Self::new(n, l, electrons) {
+            Err(Error::OverfilledSuborbital { capacity, .. }) => Err(Error::OverfilledSuborbital {
+                suborbital: s.to_string(),
+                capacity,
+            }),
+            result => result,
+        }
+    }
+}
+
+macro_rules! impl_from_suborbital {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl From<$t> for Subshell {
+                fn from(suborbital: $t) -> Self {
+                    Self {
+                        n: suborbital.quantum_number(),
+                        l: suborbital.angular_momentum(),
+                        electrons: suborbital.electrons(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_suborbital! {
+    SOrbital,
+    POrbital,
+    DOrbital,
+    FOrbital,
+    GOrbital,
+    HOrbital,
+}
+
+impl ElectronConfiguration {
+    /// Occupied subshells in shell order, by n and then by l
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// let iron = ElectronConfiguration::ground_state(26).unwrap();
+    /// let d_block = iron.iter_by_shell().filter(|s| s.block() == 'd').collect::<Vec<_>>();
+    ///
+    /// assert_eq!(d_block, ["3d6".parse::<Subshell>().unwrap()]);
+    /// ```
+    pub fn iter_by_shell(&self) -> impl Iterator<Item = Subshell> + '_ {
+        self.occupied().map(|(n, block, electrons)| Subshell {
+            n,
+            l: block_angular_momentum(block),
+            electrons,
+        })
+    }
+
+    /// Occupied subshells in Madelung order, by n + l and then by n
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// let iron = ElectronConfiguration::ground_state(26).unwrap();
+    /// let last = iron.iter_by_energy().last().unwrap();
+    ///
+    /// assert_eq!(last.to_string(), "3d6");
+    /// ```
+    pub fn iter_by_energy(&self) -> impl Iterator<Item = Subshell> {
+        self.iter_in(SubshellOrder::Energy)
+    }
+
+    /// Occupied subshells in the given order
+    pub fn iter_in(&self, order: SubshellOrder) -> impl Iterator<Item = Subshell> {
+        let mut subshells = self.iter_by_shell().collect::<Vec<_>>();
+
+        if order == SubshellOrder::Energy {
+            subshells.sort_by_key(|s| (s.n + s.l, s.n));
+        }
+
+        subshells.into_iter()
+    }
+}
+
+/// Block of a subshell, without its energy level or electron count
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SubshellKind {
+    S,
+    P,
+    D,
+    F,
+    G,
+    H,
+}
+
+impl SubshellKind {
+    /// Every kind, indexed by angular momentum
+    pub const ALL: [Self; 6] = [Self::S, Self::P, Self::D, Self::F, Self::G, Self::H];
+
+    pub const fn angular_momentum(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Letter of the block, e.g. `d`
+    pub const fn letter(&self) -> char {
+        BLOCKS[*self as usize]
+    }
+}
+
+/// Order in which to list the subshells of an [`ElectronConfiguration`]
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub enum SubshellOrder {
+    /// By principal quantum number, then angular momentum, e.g. `3d6 4s2`
+    #[default]
+    Shell,
+    /// By Madelung filling order, e.g. `4s2 3d6`
+    Energy,
+}
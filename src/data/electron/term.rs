@@ -0,0 +1,248 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use super::configuration::{ElectronConfiguration, SUPERSCRIPT_DIGITS};
+use super::orbital::{block_angular_momentum, block_boxes, block_capacity, OrbitalBox};
+
+const SUBSCRIPT_DIGITS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+
+/// Letters of total orbital angular momentum, `J` is skipped by convention
+const L_LETTERS: [char; 21] = [
+    'S', 'P', 'D', 'F', 'G', 'H', 'I', 'K', 'L', 'M', 'N', 'O', 'Q', 'R', 'T', 'U', 'V', 'W', 'X',
+    'Y', 'Z',
+];
+
+/// Russell–Saunders term symbol, written ²ˢ⁺¹L_J
+///
+/// Spin and total angular momentum are stored doubled, so half-integer values
+/// stay exact.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TermSymbol {
+    /// Twice the total spin, 2S
+    pub two_s: u8,
+    /// Total orbital angular momentum, L
+    pub l: u8,
+    /// Twice the total angular momentum, 2J
+    pub two_j: u8,
+}
+
+impl TermSymbol {
+    /// Spin multiplicity, 2S + 1
+    pub const fn multiplicity(&self) -> u8 {
+        self.two_s + 1
+    }
+
+    /// Total spin, S
+    pub fn spin(&self) -> f64 {
+        self.two_s as f64 / 2.0
+    }
+
+    /// Total angular momentum, J
+    pub fn j(&self) -> f64 {
+        self.two_j as f64 / 2.0
+    }
+
+    /// Landé g-factor of the level, taking the electron g-factor as 2
+    ///
+    /// Zero for levels with J = 0, which have no magnetic moment
+    pub fn lande_g(&self) -> f64 {
+        let j = self.j() * (self.j() + 1.0);
+        let s = self.spin() * (self.spin() + 1.0);
+        let l = self.l as f64 * (self.l as f64 + 1.0);
+
+        match self.two_j {
+            0 => 0.0,
+            _ => 1.0 + (j + s - l) / (2.0 * j),
+        }
+    }
+
+    /// Every J level of the term with the given spin and angular momentum
+    fn levels(two_s: u8, l: u8) -> impl Iterator<Item = Self> {
+        let min = (2 * l as i16 - two_s as i16).unsigned_abs() as u8;
+        let max = 2 * l + two_s;
+
+        (min..=max)
+            .step_by(2)
+            .map(move |two_j| Self { two_s, l, two_j })
+    }
+}
+
+impl fmt::Display for TermSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = |n: u8, set: &[char; 10]| {
+            n.to_string()
+                .chars()
+                .map(|c| set[c.to_digit(10).expect("Digit") as usize])
+                .collect::<String>()
+        };
+
+        let letter = match L_LETTERS.get(self.l as usize) {
+            Some(letter) => letter.to_string(),
+            None => format!("({})", self.l),
+        };
+
+        let j = match self.two_j % 2 {
+            0 => digits(self.two_j / 2, &SUBSCRIPT_DIGITS),
+            _ => format!(
+                "{}/{}",
+                digits(self.two_j, &SUBSCRIPT_DIGITS),
+                SUBSCRIPT_DIGITS[2]
+            ),
+        };
+
+        write!(
+            f,
+            "{}{letter}{j}",
+            digits(self.multiplicity(), &SUPERSCRIPT_DIGITS)
+        )
+    }
+}
+
+impl ElectronConfiguration {
+    /// Ground term by Hund's rules
+    ///
+    /// Open suborbitals are filled to maximise spin, then orbital angular
+    /// momentum. J is |L - S| unless an open suborbital is more than half
+    /// full, in which case it is L + S. Closed shell configurations are ¹S₀.
+    ///
+    /// Hund's rules are only an approximation for several open suborbitals,
+    /// e.g. cerium is predicted to be ³H₄ rather than the observed ¹G₄.
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// let carbon = ElectronConfiguration::ground_state(6).unwrap();
+    /// let manganese = ElectronConfiguration::ground_state(25).unwrap();
+    ///
+    /// assert_eq!(carbon.term_symbol().to_string(), "³P₀");
+    /// assert_eq!(manganese.term_symbol().to_string(), "⁶S₅/₂");
+    /// ```
+    pub fn term_symbol(&self) -> TermSymbol {
+        let mut two_ms = 0i16;
+        let mut ml = 0i16;
+        let mut more_than_half = false;
+
+        for (_, block, e) in self.open() {
+            for OrbitalBox {
+                magnetic_quantum_number,
+                spin_up,
+                spin_down,
+            } in block_boxes(block, e)
+            {
+                two_ms += spin_up as i16 - spin_down as i16;
+                ml += magnetic_quantum_number as i16 * (spin_up as i16 + spin_down as i16);
+            }
+
+            more_than_half |= e > block_capacity(block) / 2;
+        }
+
+        let two_s = two_ms.unsigned_abs() as u8;
+        let l = ml.unsigned_abs() as u8;
+
+        let two_j = match more_than_half {
+            true => 2 * l + two_s,
+            false => (2 * l as i16 - two_s as i16).unsigned_abs() as u8,
+        };
+
+        TermSymbol { two_s, l, two_j }
+    }
+
+    /// Every term arising from the microstates of the open suborbitals
+    ///
+    /// Each J level is listed separately, ordered by decreasing multiplicity,
+    /// then decreasing L, then increasing J. Terms occurring more than once,
+    /// such as the two ²D terms of d³, are repeated.
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// let carbon = ElectronConfiguration::ground_state(6).unwrap();
+    /// let terms = carbon
+    ///     .all_terms()
+    ///     .iter()
+    ///     .map(TermSymbol::to_string)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(terms, ["³P₀", "³P₁", "³P₂", "¹D₂", "¹S₀"]);
+    /// ```
+    pub fn all_terms(&self) -> Vec<TermSymbol> {
+        // Number of microstates for every (M_L, 2M_S) pair
+        let mut counts = BTreeMap::from([((0i16, 0i16), 1u64)]);
+
+        for (_, block, e) in self.open() {
+            let sub = microstates(block, e);
+            let mut combined = BTreeMap::new();
+
+            for (&(ml, two_ms), &n) in &counts {
+                for (&(sub_ml, sub_two_ms), &m) in &sub {
+                    *combined
+                        .entry((ml + sub_ml, two_ms + sub_two_ms))
+                        .or_insert(0) += n * m;
+                }
+            }
+
+            counts = combined;
+        }
+
+        let count = |ml: i16, two_ms: i16| counts.get(&(ml, two_ms)).copied().unwrap_or(0);
+        let mut terms = Vec::new();
+
+        // Each term with L = M_L and S = M_S has one microstate at (M_L, M_S),
+        // so the number of such terms is the count there less those belonging
+        // to terms with a larger L or S
+        for &(l, two_s) in counts
+            .keys()
+            .filter(|&&(ml, two_ms)| ml >= 0 && two_ms >= 0)
+        {
+            let repeats = (count(l, two_s) + count(l + 1, two_s + 2))
+                - (count(l + 1, two_s) + count(l, two_s + 2));
+
+            for _ in 0..repeats {
+                terms.extend(TermSymbol::levels(two_s as u8, l as u8));
+            }
+        }
+
+        terms.sort_by_key(|t| (std::cmp::Reverse(t.two_s), std::cmp::Reverse(t.l), t.two_j));
+
+        terms
+    }
+
+    /// Quantum number, block letter and electron count of partially filled
+    /// suborbitals
+    fn open(&self) -> impl Iterator<Item = (u8, char, u8)> + '_ {
+        self.occupied()
+            .filter(|&(_, block, e)| e < block_capacity(block))
+    }
+}
+
+/// Number of microstates of `electrons` in a suborbital for every
+/// (M_L, 2M_S) pair
+fn microstates(block: char, electrons: u8) -> BTreeMap<(i16, i16), u64> {
+    let l = block_angular_momentum(block) as i16;
+    let capacity = block_capacity(block) as u32;
+
+    let mut counts = BTreeMap::new();
+
+    // Bit 2i is spin up and bit 2i + 1 is spin down in orbital m_l = l - i
+    for state in 0u32..(1 << capacity) {
+        if state.count_ones() != electrons as u32 {
+            continue;
+        }
+
+        let (ml, two_ms) = (0..capacity).filter(|bit| state & (1 << bit) != 0).fold(
+            (0, 0),
+            |(ml, two_ms), bit| {
+                let spin = match bit % 2 {
+                    0 => 1,
+                    _ => -1,
+                };
+
+                (ml + l - (bit / 2) as i16, two_ms + spin)
+            },
+        );
+
+        *counts.entry((ml, two_ms)).or_insert(0) += 1;
+    }
+
+    counts
+}
@@ -0,0 +1,104 @@
+use super::configuration::ElectronConfiguration;
+use super::orbital::block_capacity;
+use super::subshell::Subshell;
+
+impl ElectronConfiguration {
+    /// Number of valence electrons, counted by block so that they match the
+    /// group number
+    ///
+    /// Electrons inside the largest noble gas core are never counted. Beyond
+    /// that
+    ///
+    /// - p-block: only the outer s and p electrons, e.g. 3 for gallium
+    /// - d- and s-block: the outer s and d electrons, e.g. 8 for iron
+    /// - f- and g-block: every electron outside the core, as f and g electrons
+    ///   have no group, e.g. 10 for gadolinium
+    ///
+    /// Filled d and f suborbitals below the outer shell are skipped, so
+    /// lutetium and hafnium have 3 and 4.
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// let count = |z| ElectronConfiguration::ground_state(z).unwrap().valence_electrons();
+    ///
+    /// assert_eq!(count(17), 7);
+    /// assert_eq!(count(26), 8);
+    /// assert_eq!(count(31), 3);
+    /// ```
+    pub fn valence_electrons(&self) -> u8 {
+        let outside = self.outside_core();
+
+        let has = |block: char| outside.iter().any(|&(_, b, _)| b == block);
+        let partial = |block: char| {
+            outside
+                .iter()
+                .any(|&(_, b, e)| b == block && e < block_capacity(block))
+        };
+
+        let counted: &[char] = match (has('p'), partial('f') || partial('g')) {
+            (true, _) => &['s', 'p'],
+            (false, true) => &['s', 'd', 'f', 'g'],
+            (false, false) => &['s', 'd'],
+        };
+
+        outside
+            .iter()
+            .filter(|&&(_, block, e)| {
+                counted.contains(&block)
+                    && !(matches!(block, 'f' | 'g') && e == block_capacity(block))
+            })
+            .map(|&(_, _, e)| e)
+            .sum()
+    }
+
+    /// Number of electrons which are not valence electrons, see
+    /// [`ElectronConfiguration::valence_electrons`]
+    pub fn core_electrons(&self) -> u8 {
+        self.electron_count() as u8 - self.valence_electrons()
+    }
+
+    /// Principal quantum number of the highest occupied shell
+    pub fn valence_shell(&self) -> Option<u8> {
+        self.occupied().map(|(n, _, _)| n).max()
+    }
+
+    /// Number of electrons in the highest occupied shell, as used for Lewis
+    /// structures of main group elements
+    pub fn outer_shell_electrons(&self) -> u8 {
+        match self.valence_shell() {
+            Some(shell) => self
+                .occupied()
+                .filter(|&(n, _, _)| n == shell)
+                .map(|(_, _, e)| e)
+                .sum(),
+            None => 0,
+        }
+    }
+
+    /// Highest energy occupied suborbital of the highest occupied shell
+    ///
+    /// ```
+    /// use chemistru_elements::data::prelude::*;
+    ///
+    /// let iron = ElectronConfiguration::ground_state(26).unwrap();
+    ///
+    /// assert_eq!(iron.outermost_subshell().unwrap().to_string(), "4s2");
+    /// ```
+    pub fn outermost_subshell(&self) -> Option<Subshell> {
+        self.iter_by_shell().max_by_key(|s| s.n())
+    }
+
+    /// Occupied suborbitals outside the largest noble gas core, or all of them
+    /// if there is no core
+    fn outside_core(&self) -> Vec<(u8, char, u8)> {
+        let core = self.noble_gas_core();
+
+        self.occupied()
+            .filter(|&(n, block, e)| match &core {
+                Some((_, core)) => core.electrons_in(n, block) != e,
+                None => true,
+            })
+            .collect()
+    }
+}
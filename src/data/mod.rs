@@ -1,11 +1,35 @@
 pub mod atomic;
+pub mod display;
 pub mod electron;
+pub mod physical;
 pub mod state;
+pub mod table;
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
 
 pub mod prelude {
     use super::*;
 
     pub use atomic::AtomicData;
-    pub use electron::{configuration::ElectronConfiguration, orbital::*, ElectronData};
+    pub use display::DisplayData;
+    pub use electron::{
+        configuration::{DisplayOptions, ElectronConfiguration},
+        excitation::SubshellDiff,
+        orbital::*,
+        quantum::QuantumNumbers,
+        subshell::{Subshell, SubshellKind, SubshellOrder},
+        term::TermSymbol,
+        ElectronData,
+    };
+    pub use physical::PhysicalData;
     pub use state::StateData;
+    pub use table::TableData;
+}
+
+fn option_to_tokens<T: ToTokens>(o: Option<T>) -> TokenStream {
+    match o {
+        Some(v) => quote!(Some(#v)),
+        None => quote!(None),
+    }
 }
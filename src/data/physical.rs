@@ -0,0 +1,35 @@
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use quote::{quote, ToTokens, TokenStreamExt};
+
+use super::option_to_tokens;
+
+/// Bulk physical properties of an element
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct PhysicalData {
+    /// Density in g/cm³
+    pub density: Option<f64>,
+    /// Phase at standard temperature and pressure, e.g. `Gas`
+    pub phase: &'static str,
+    /// Molar heat capacity in J/(mol·K)
+    pub molar_heat: Option<f64>,
+}
+
+impl ToTokens for PhysicalData {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let density = option_to_tokens(self.density);
+        let phase = self.phase;
+        let molar_heat = option_to_tokens(self.molar_heat);
+
+        let add_tokens = quote! {
+            chemistru_elements::data::physical::PhysicalData {
+                density: #density,
+                phase: #phase,
+                molar_heat: #molar_heat,
+            }
+        };
+
+        let group = TokenTree::Group(Group::new(Delimiter::None, add_tokens));
+
+        tokens.append(group);
+    }
+}
@@ -0,0 +1,39 @@
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use quote::{quote, ToTokens, TokenStreamExt};
+
+/// Position and classification of an element in the periodic table
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct TableData {
+    /// Category of element, e.g. `transition metal`
+    pub category: &'static str,
+    /// Row of the element in the periodic table
+    pub period: u8,
+    /// Column of the element in a rendered table, starting at 1
+    pub xpos: u8,
+    /// Row of the element in a rendered table, starting at 1
+    ///
+    /// Lanthanides and actinides are placed in rows 9 and 10
+    pub ypos: u8,
+}
+
+impl ToTokens for TableData {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let category = self.category;
+        let period = self.period;
+        let xpos = self.xpos;
+        let ypos = self.ypos;
+
+        let add_tokens = quote! {
+            chemistru_elements::data::table::TableData {
+                category: #category,
+                period: #period,
+                xpos: #xpos,
+                ypos: #ypos,
+            }
+        };
+
+        let group = TokenTree::Group(Group::new(Delimiter::None, add_tokens));
+
+        tokens.append(group);
+    }
+}
@@ -1,56 +1,161 @@
+use std::num::NonZeroU8;
+
+use crate::data::prelude::{ElectronConfiguration, Subshell};
+use crate::error::Result;
 use crate::inner::InnerElement;
+use crate::symbol::ElementSymbol;
+use crate::table;
 
-/// Small-ish Representation of an Element
-#[derive(Copy, Debug, Clone, PartialOrd)]
-pub struct Element {
-    name: &'static str,
-    symbol: &'static str,
-    atomic_mass: f64,
-    atomic_number: u8,
-    raw: &'static InnerElement,
-}
+/// One byte handle to an element in the built-in table
+///
+/// Only the atomic number is stored, everything else is looked up in
+/// [`table::ELEMENTS`], so large molecular structures stay small. The niche
+/// in [`NonZeroU8`] also keeps `Option<Element>` to a single byte.
+///
+/// Equality, ordering and hashing all use the atomic number, so sorting gives
+/// periodic order and elements can be used as map keys.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use chemistru_elements::element::Element;
+///
+/// let mut elements = ["Fe", "H", "U", "O"].map(|s| Element::from_symbol(s).unwrap());
+/// elements.sort();
+///
+/// assert_eq!(elements.map(|e| e.atomic_number()), [1, 8, 26, 92]);
+///
+/// let counts = HashMap::from([(elements[0], 2), (elements[1], 1)]);
+///
+/// assert_eq!(counts[&Element::from_atomic_number(1).unwrap()], 2);
+/// assert_eq!(counts.get(&elements[2]), None);
+/// ```
+#[derive(Copy, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Element(NonZeroU8);
 
 impl Element {
     #[inline]
     pub fn name(&self) -> String {
-        self.name.to_string()
+        self.data().name.to_string()
     }
 
     #[inline]
     pub fn symbol(&self) -> String {
-        self.symbol.to_string()
+        self.data().symbol.to_string()
     }
 
     #[inline]
-    pub const fn atomic_mass(&self) -> f64 {
-        self.atomic_mass
+    pub fn atomic_mass(&self) -> f64 {
+        self.data().atomic_data.atomic_mass
     }
 
     #[inline]
     pub const fn atomic_number(&self) -> u8 {
-        self.atomic_number
+        self.0.get()
+    }
+
+    #[inline]
+    pub fn data(&self) -> &'static InnerElement {
+        table::ELEMENTS[self.0.get() as usize - 1]
     }
 
-    pub const fn data(&self) -> &'static InnerElement {
-        self.raw
+    /// Symbol of the element as an enum, allowing exhaustive matching
+    #[inline]
+    pub fn symbol_enum(&self) -> ElementSymbol {
+        ElementSymbol::try_from(self.0.get())
+            .expect("Element has an atomic number outside of the periodic table")
     }
 
+    /// Element with the given symbol, usable in constants
     #[inline]
-    pub const fn new(
-        name: &'static str,
-        symbol: &'static str,
-        atomic_mass: f64,
-        atomic_number: u8,
-        raw: &'static InnerElement,
-    ) -> Self {
-        Self {
-            name,
-            symbol,
-            atomic_mass,
-            atomic_number,
-            raw,
+    pub const fn from_symbol_enum(symbol: ElementSymbol) -> Self {
+        match NonZeroU8::new(symbol as u8) {
+            Some(n) => Self(n),
+            None => unreachable!(),
         }
     }
+
+    /// Ground state electron configuration from the built-in table
+    #[inline]
+    pub fn electron_configuration(&self) -> &'static ElectronConfiguration {
+        &self.data().electron_data.electron_configuration
+    }
+
+    /// Number of valence electrons in the ground state, see
+    /// [`ElectronConfiguration::valence_electrons`]
+    pub fn valence_electrons(&self) -> u8 {
+        self.electron_configuration().valence_electrons()
+    }
+
+    /// Number of core electrons in the ground state
+    pub fn core_electrons(&self) -> u8 {
+        self.electron_configuration().core_electrons()
+    }
+
+    /// Principal quantum number of the highest occupied shell
+    pub fn valence_shell(&self) -> u8 {
+        self.electron_configuration()
+            .valence_shell()
+            .expect("Every element has electrons")
+    }
+
+    /// Highest energy occupied suborbital of the ground state
+    pub fn outermost_subshell(&self) -> Subshell {
+        self.electron_configuration()
+            .outermost_subshell()
+            .expect("Every element has electrons")
+    }
+
+    /// Effective nuclear charge felt by an electron in suborbital `(n, l)` of
+    /// the ground state, see
+    /// [`ElectronConfiguration::effective_nuclear_charge`]
+    pub fn z_eff(&self, n: u8, l: u8) -> Result<f64> {
+        self.electron_configuration()
+            .effective_nuclear_charge(self.atomic_number(), n, l)
+    }
+
+    /// Look up an element in the built-in table by its symbol, ignoring case
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        let mut buf = [0u8; 16];
+
+        table::BY_SYMBOL
+            .get(lowercase(symbol, &mut buf)?)
+            .map(|&idx| Self::from_index(idx))
+    }
+
+    /// Look up an element in the built-in table by its name, ignoring case
+    pub fn from_name(name: &str) -> Option<Self> {
+        let mut buf = [0u8; 16];
+
+        table::BY_NAME
+            .get(lowercase(name, &mut buf)?)
+            .map(|&idx| Self::from_index(idx))
+    }
+
+    /// Look up an element in the built-in table by its atomic number
+    pub fn from_atomic_number(atomic_number: u8) -> Option<Self> {
+        let n = NonZeroU8::new(atomic_number)?;
+
+        (n.get() as usize <= table::ELEMENTS.len()).then_some(Self(n))
+    }
+
+    /// Built-in table is ordered by atomic number, starting at hydrogen
+    #[inline]
+    fn from_index(idx: usize) -> Self {
+        Self(NonZeroU8::new(idx as u8 + 1).expect("Table index is below 255"))
+    }
+}
+
+/// Lowercase ASCII input into a stack buffer so lookups do not allocate
+///
+/// Returns `None` if the input is too long to be the key of any element
+fn lowercase<'a>(s: &str, buf: &'a mut [u8; 16]) -> Option<&'a str> {
+    let buf = buf.get_mut(..s.len())?;
+
+    buf.copy_from_slice(s.as_bytes());
+    buf.make_ascii_lowercase();
+
+    std::str::from_utf8(buf).ok()
 }
 
 pub trait Elemental {
@@ -87,9 +192,3 @@ impl Elemental for Element {
         self.data()
     }
 }
-
-impl PartialEq for Element {
-    fn eq(&self, other: &Self) -> bool {
-        (self.atomic_mass, self.atomic_number) == (other.atomic_mass, other.atomic_number)
-    }
-}
@@ -0,0 +1,145 @@
+use std::fmt;
+use std::io;
+
+use crate::data::electron::quantum::QuantumNumbers;
+
+/// Errors produced while parsing or validating element data
+#[derive(Debug)]
+pub enum Error {
+    /// Suborbital notation such as `3d6` could not be parsed
+    InvalidSuborbital(String),
+    /// Suborbital holds more electrons than its capacity
+    OverfilledSuborbital {
+        suborbital: String,
+        capacity: u8,
+    },
+    /// Electron removed from a suborbital which has none
+    EmptySuborbital(String),
+    /// Principal quantum number outside of the supported energy levels
+    InvalidQuantumNumber(u8),
+    /// Angular momentum quantum number not below the principal quantum
+    /// number, or beyond the h block
+    InvalidAngularMomentum(u8),
+    /// Magnetic quantum number with a magnitude above the angular momentum
+    InvalidMagneticQuantumNumber(i8),
+    /// Suborbitals of an energy level disagree on its quantum number
+    MismatchedQuantumNumbers([u8; 6]),
+    /// More electrons than the supported energy levels can hold
+    TooManyElectrons(u8),
+    /// More electron shells than the supported energy levels
+    TooManyShells(usize),
+    /// Shorthand configuration refers to something other than a noble gas
+    UnknownNobleGasCore(String),
+    /// Noble gas core written after the first subshell of a configuration
+    MisplacedNobleGasCore(String),
+    /// Subshell given more than once in a configuration, or also in its core
+    DuplicateSuborbital(String),
+    /// String is not the symbol of any element
+    UnknownSymbol(String),
+    /// Charge removes more electrons than there are, or adds more than fit
+    InvalidCharge(i8),
+    /// Number is not the atomic number of any element
+    InvalidAtomicNumber(u8),
+    /// Crystal field splitting of an element outside of groups 3 to 12
+    NotTransitionMetal(String),
+    /// Ion with other than one electron treated as hydrogen-like
+    NotHydrogenic(String),
+    /// Emission must fall from a higher level to a lower, non-zero level
+    InvalidTransition {
+        upper: u8,
+        lower: u8,
+    },
+    /// Quantum numbers that do not describe an allowed state
+    InvalidQuantumNumbers(QuantumNumbers),
+    /// Two electrons share the same quantum numbers
+    PauliExclusion(QuantumNumbers),
+    /// Error in the data of a named element
+    Element {
+        name: String,
+        source: Box<Error>,
+    },
+    Io(io::Error),
+    Json(serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Attach the name of the element whose data caused the error
+    pub fn in_element(self, name: impl Into<String>) -> Self {
+        Self::Element {
+            name: name.into(),
+            source: Box::new(self),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSuborbital(s) => write!(f, "Invalid suborbital [{s}]"),
+            Self::OverfilledSuborbital {
+                suborbital,
+                capacity,
+            } => write!(
+                f,
+                "Suborbital [{suborbital}] holds more than {capacity} electrons"
+            ),
+            Self::EmptySuborbital(s) => write!(f, "Suborbital [{s}] has no electrons"),
+            Self::InvalidQuantumNumber(n) => write!(f, "Invalid quantum number [{n}]"),
+            Self::InvalidAngularMomentum(l) => write!(f, "Invalid angular momentum [{l}]"),
+            Self::InvalidMagneticQuantumNumber(m) => {
+                write!(f, "Invalid magnetic quantum number [{m}]")
+            }
+            Self::MismatchedQuantumNumbers([s, p, d, f_, g, h]) => write!(
+                f,
+                "Suborbitals of energy level have different quantum numbers [s: {s}, p: {p}, d: {d}, f: {f_}, g: {g}, h: {h}]"
+            ),
+            Self::TooManyElectrons(n) => write!(f, "Too many electrons [{n}]"),
+            Self::TooManyShells(n) => write!(f, "Too many electron shells [{n}]"),
+            Self::UnknownNobleGasCore(s) => write!(f, "Unknown noble gas core [{s}]"),
+            Self::MisplacedNobleGasCore(s) => {
+                write!(f, "Noble gas core is not at the start of configuration [{s}]")
+            }
+            Self::DuplicateSuborbital(s) => write!(f, "Suborbital given more than once [{s}]"),
+            Self::UnknownSymbol(s) => write!(f, "Unknown element symbol [{s}]"),
+            Self::InvalidCharge(c) => write!(f, "Invalid charge [{c}]"),
+            Self::InvalidAtomicNumber(n) => write!(f, "Invalid atomic number [{n}]"),
+            Self::NotTransitionMetal(s) => write!(f, "Not a transition metal [{s}]"),
+            Self::NotHydrogenic(s) => write!(f, "Not a single electron species [{s}]"),
+            Self::InvalidTransition { upper, lower } => {
+                write!(f, "Invalid transition [{upper} -> {lower}]")
+            }
+            Self::InvalidQuantumNumbers(q) => write!(f, "Invalid quantum numbers [{q}]"),
+            Self::PauliExclusion(q) => {
+                write!(f, "Quantum numbers shared by two electrons [{q}]")
+            }
+            Self::Element { name, source } => write!(f, "{name}: {source}"),
+            Self::Io(e) => write!(f, "{e}"),
+            Self::Json(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Element { source, .. } => Some(source.as_ref()),
+            Self::Io(e) => Some(e),
+            Self::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
@@ -1,3 +1,6 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
 use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
 use quote::{quote, ToTokens, TokenStreamExt};
 
@@ -8,14 +11,51 @@ use super::data::prelude::*;
 /// Should be held behind avstatic reference where possible due to large size
 ///
 /// Large size is due to being made able to be static or constant
-#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+///
+/// Equality, ordering and hashing only consider the atomic number
+#[derive(Copy, Clone, Debug)]
 pub struct InnerElement {
     pub name: &'static str,
     pub symbol: &'static str,
     pub description: &'static str,
+    /// Who named the element
+    pub named_by: Option<&'static str>,
+    /// URL the element's information was sourced from
+    pub source: &'static str,
     pub atomic_data: AtomicData,
     pub state_data: StateData,
     pub electron_data: ElectronData,
+    pub physical_data: PhysicalData,
+    pub table_data: TableData,
+    pub display_data: DisplayData,
+}
+
+impl PartialEq for InnerElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.atomic_data.atomic_number == other.atomic_data.atomic_number
+    }
+}
+
+impl Eq for InnerElement {}
+
+impl PartialOrd for InnerElement {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for InnerElement {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.atomic_data
+            .atomic_number
+            .cmp(&other.atomic_data.atomic_number)
+    }
+}
+
+impl Hash for InnerElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.atomic_data.atomic_number.hash(state);
+    }
 }
 
 impl ToTokens for InnerElement {
@@ -23,18 +63,31 @@ impl ToTokens for InnerElement {
         let name = self.name;
         let symbol = self.symbol;
         let desc = self.description;
+        let named_by = match self.named_by {
+            Some(v) => quote!(Some(#v)),
+            None => quote!(None),
+        };
+        let source = self.source;
         let atomic_data = self.atomic_data;
         let state_data = self.state_data;
         let electron_data = self.electron_data;
+        let physical_data = self.physical_data;
+        let table_data = self.table_data;
+        let display_data = self.display_data;
 
         let tokens = quote! {
             &chemistru_elements::inner::InnerElement {
                 name: #name,
                 symbol: #symbol,
                 description: #desc,
+                named_by: #named_by,
+                source: #source,
                 atomic_data: #atomic_data,
                 state_data: #state_data,
                 electron_data: #electron_data,
+                physical_data: #physical_data,
+                table_data: #table_data,
+                display_data: #display_data,
             }
         };
 
@@ -1,13 +1,81 @@
 use crate::{
+    data::electron::configuration::ENERGY_LEVELS,
+    data::prelude::ElectronConfiguration,
     element::{Element, Elemental},
+    error::Result,
     inner::InnerElement,
 };
 
+/// Element with a charge
+///
+/// Ordered by element first, then by oxidation state
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Ion {
     pub element: Element,
     pub oxidation_state: i8,
 }
 
+impl Ion {
+    /// Electron configuration derived from that of the neutral element
+    ///
+    /// See [`ElectronConfiguration::ionised`] for the order electrons are
+    /// removed and added in
+    pub fn electron_configuration(&self) -> Result<ElectronConfiguration> {
+        self.element
+            .data()
+            .electron_data
+            .electron_configuration
+            .ionised(self.oxidation_state)
+    }
+
+    /// Spin-only magnetic moment in Bohr magnetons, √(n(n + 2)) for n
+    /// unpaired electrons
+    ///
+    /// A good estimate for first row transition metal ions, whose orbital
+    /// angular momentum is largely quenched.
+    ///
+    /// ```
+    /// use chemistru_elements::ion::Ion;
+    /// use chemistru_elements::table::IRON;
+    ///
+    /// let iron = Ion { element: IRON, oxidation_state: 3 };
+    ///
+    /// assert!((iron.spin_only_magnetic_moment().unwrap() - 5.92).abs() < 0.01);
+    /// ```
+    pub fn spin_only_magnetic_moment(&self) -> Result<f64> {
+        let n = self.electron_configuration()?.unpaired_electrons() as f64;
+
+        Ok((n * (n + 2.0)).sqrt())
+    }
+
+    /// Magnetic moment in Bohr magnetons from the ground term,
+    /// g_J √(J(J + 1))
+    ///
+    /// Suited to lanthanide ions, whose 4f electrons keep their orbital
+    /// angular momentum. Ions whose ground level has J = 0, such as
+    /// europium(III), are predicted to have no moment.
+    ///
+    /// ```
+    /// use chemistru_elements::ion::Ion;
+    /// use chemistru_elements::table::DYSPROSIUM;
+    ///
+    /// let dysprosium = Ion { element: DYSPROSIUM, oxidation_state: 3 };
+    ///
+    /// assert!((dysprosium.effective_magnetic_moment().unwrap() - 10.65).abs() < 0.01);
+    /// ```
+    pub fn effective_magnetic_moment(&self) -> Result<f64> {
+        let term = self.electron_configuration()?.term_symbol();
+        let j = term.j();
+
+        Ok(term.lande_g() * (j * (j + 1.0)).sqrt())
+    }
+
+    /// Number of electrons in each shell of the ion
+    pub fn shells(&self) -> Result<[u8; ENERGY_LEVELS]> {
+        Ok(self.electron_configuration()?.shell_counts())
+    }
+}
+
 impl Elemental for Ion {
     fn name(&self) -> String {
         self.element.name()
@@ -4,9 +4,25 @@
 //! memory efficient format, allowing construction of complex molecules that do
 //! not destroy the performance of the program.
 
+// Allows code generated through `ToTokens` to be included in this crate
+extern crate self as chemistru_elements;
+
+pub mod codegen;
+pub mod crystal_field;
 pub mod data;
 pub mod element;
+pub mod error;
 pub mod inner;
 pub mod ion;
 pub mod raw;
 pub mod reaction;
+pub mod spectra;
+pub mod symbol;
+pub mod table;
+pub mod wavefunction;
+
+pub use error::{Error, Result};
+
+// Used by generated lookup maps, so downstream crates need no direct dependency
+#[doc(hidden)]
+pub use phf;
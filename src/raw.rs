@@ -1,8 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::data::electron::orbital;
+use crate::data::electron::configuration::ENERGY_LEVELS;
+use crate::error::{Error, Result};
 use crate::inner::InnerElement;
-use crate::suborbital;
 
 use super::data::prelude::*;
 
@@ -34,70 +34,59 @@ pub struct RawElement {
     pub electron_affinity: Option<f64>,
     pub electronegativity_pauling: Option<f64>,
     pub ionization_energies: Vec<f64>,
+    #[serde(rename = "cpk-hex", alias = "cpk_hex")]
     pub cpk_hex: Option<&'static str>,
 }
 
 impl RawElement {
-    pub fn into_inner(self) -> InnerElement {
-        let sub_orbitals = self
-            .electron_configuration
-            .split(" ")
-            .map(parse_suborbital)
-            .collect::<Vec<_>>();
-
-        let mut electron_configuration = [1u8, 2, 3, 4, 5, 6, 7, 8].map(|i| EnergyLevel {
-            s: suborbital!(s, i, 0),
-            p: suborbital!(p, i, 0),
-            d: suborbital!(d, i, 0),
-            f: suborbital!(f, i, 0),
-        });
-
-        sub_orbitals.iter().for_each(|so| match so.capacity() {
-            2 => {
-                *electron_configuration[so.quantum_number() as usize - 1]
-                    .s
-                    .electrons_as_mut() = so.electrons()
-            }
-            6 => {
-                *electron_configuration[so.quantum_number() as usize - 1]
-                    .p
-                    .electrons_as_mut() = so.electrons()
-            }
-            10 => {
-                *electron_configuration[so.quantum_number() as usize - 1]
-                    .d
-                    .electrons_as_mut() = so.electrons()
-            }
-            14 => {
-                *electron_configuration[so.quantum_number() as usize - 1]
-                    .f
-                    .electrons_as_mut() = so.electrons()
-            }
-            cap => panic!("Invalid suborbital capacity [{cap}]"),
-        });
-
-        let electron_configuration = ElectronConfiguration::new(electron_configuration);
-
-        let mut ionisation_energies = [0.0f64; 30];
+    /// Convert into detailed element information
+    ///
+    /// Errors are tagged with the name of the element
+    ///
+    /// ```
+    /// use chemistru_elements::error::Error;
+    /// use chemistru_elements::raw::RawElement;
+    ///
+    /// let raw: RawElement = serde_json::from_str(r#"{
+    ///     "name": "Hydrogen", "appearance": null, "atomic_mass": 1.008, "boil": 20.271,
+    ///     "category": "diatomic nonmetal", "color": null, "density": 0.08988,
+    ///     "melt": 13.99, "molar_heat": 28.836, "named_by": "Antoine Lavoisier",
+    ///     "number": 1, "period": 1, "phase": "Gas", "source": "", "spectral_img": null,
+    ///     "summary": "", "symbol": "H", "xpos": 1, "ypos": 1, "shells": [1],
+    ///     "electron_configuration": "1s3", "electron_configuration_semantic": "1s3",
+    ///     "electron_affinity": 72.769, "electronegativity_pauling": 2.2,
+    ///     "ionization_energies": [1312.0], "cpk-hex": "ffffff"
+    /// }"#).unwrap();
+    ///
+    /// let error = raw.into_inner().unwrap_err();
+    ///
+    /// assert!(matches!(&error, Error::Element { name, .. } if name == "Hydrogen"));
+    /// assert!(error.to_string().contains("Hydrogen"));
+    /// ```
+    pub fn into_inner(self) -> Result<InnerElement> {
+        let name = self.name;
+
+        self.try_into_inner().map_err(|e| e.in_element(name))
+    }
 
-        let s = ionisation_energies.as_mut_slice();
+    fn try_into_inner(self) -> Result<InnerElement> {
+        let electron_configuration = self
+            .electron_configuration
+            .parse::<ElectronConfiguration>()?;
 
-        for (idx, &item) in self.ionization_energies.iter().enumerate() {
-            s[idx] = item;
+        if self.shells.len() > ENERGY_LEVELS {
+            return Err(Error::TooManyShells(self.shells.len()));
         }
 
-        let mut shells = [0u8; 8];
-
-        let s = shells.as_mut_slice();
+        let shells = Box::leak(self.shells.into_boxed_slice());
+        let ionisation_energies = Box::leak(self.ionization_energies.into_boxed_slice());
 
-        for (idx, &item) in self.shells.iter().enumerate() {
-            s[idx] = item;
-        }
-
-        InnerElement {
+        Ok(InnerElement {
             name: self.name,
             symbol: self.symbol,
             description: Box::leak(self.summary.into_boxed_str()),
+            named_by: self.named_by,
+            source: self.source,
             atomic_data: AtomicData {
                 atomic_number: self.number,
                 nucleon_number: self.atomic_mass.round() as u16,
@@ -109,46 +98,34 @@ impl RawElement {
             },
             electron_data: ElectronData {
                 electron_configuration,
+                electron_configuration_semantic: self.electron_configuration_semantic,
                 ionisation_energies,
                 shells,
                 electron_affinity: self.electron_affinity,
                 electronegativity: self.electronegativity_pauling,
             },
-        }
+            physical_data: PhysicalData {
+                density: self.density,
+                phase: self.phase,
+                molar_heat: self.molar_heat,
+            },
+            table_data: TableData {
+                category: self.category,
+                period: self.period,
+                xpos: self.xpos,
+                ypos: self.ypos,
+            },
+            display_data: DisplayData {
+                appearance: self.appearance,
+                color: self.color,
+                cpk_hex: self.cpk_hex,
+                spectral_img: self.spectral_img,
+            },
+        })
     }
 }
 
-pub fn parse_suborbital(s: &str) -> Box<dyn orbital::SubOrbital> {
-    let mut chars = s.chars();
-
-    let mut quantum_number = None;
-    let mut suborbital_letter = None;
-    let mut suborbital_fullness = 0u8;
-
-    while let Some(c) = chars.next() {
-        if c.is_digit(10) && suborbital_letter.is_none() {
-            quantum_number = Some(c);
-        } else if c.is_alphabetic() {
-            suborbital_letter = Some(c);
-        } else if c.is_digit(10) {
-            suborbital_fullness *= 10;
-            suborbital_fullness += c.to_digit(10).unwrap() as u8;
-        }
-    }
-
-    if let (Some(number), Some(letter), quantity) =
-        (quantum_number, suborbital_letter, suborbital_fullness)
-    {
-        let digit = number.to_digit(10).unwrap();
-
-        match letter {
-            's' => Box::new(suborbital!(s, digit as u8, quantity)),
-            'p' => Box::new(suborbital!(p, digit as u8, quantity)),
-            'd' => Box::new(suborbital!(d, digit as u8, quantity)),
-            'f' => Box::new(suborbital!(f, digit as u8, quantity)),
-            _ => panic!("Invalid suborbital letter"),
-        }
-    } else {
-        panic!("Invalid orbital number, suborbital letter, or electron quantity");
-    }
+/// Parse a suborbital in the form `3d6`
+pub fn parse_suborbital(s: &str) -> Result<Subshell> {
+    s.parse()
 }
@@ -0,0 +1,232 @@
+//! Energy levels and spectral lines of hydrogen-like, single electron species
+//!
+//! Energies follow E_n = −Z²R/n², with the Rydberg energy corrected for the
+//! reduced mass of the electron and nucleus. Wavelengths are in vacuum.
+//!
+//! ```
+//! use chemistru_elements::spectra::{Hydrogenic, Series};
+//! use chemistru_elements::table::HYDROGEN;
+//!
+//! let hydrogen = Hydrogenic::new(HYDROGEN);
+//! let h_alpha = hydrogen.series(Series::Balmer, 1)[0];
+//!
+//! assert!((h_alpha.wavelength() - 656.47).abs() < 0.01);
+//! assert_eq!(h_alpha.colour(), Some([255, 0, 0]));
+//! ```
+
+use crate::element::{Element, Elemental};
+use crate::error::{Error, Result};
+use crate::ion::Ion;
+
+/// Rydberg energy for an infinitely heavy nucleus, in eV
+pub const RYDBERG_ENERGY: f64 = 13.605_693_122_994;
+
+/// Planck constant multiplied by the speed of light, in eV nm
+pub const PLANCK_SPEED_OF_LIGHT: f64 = 1_239.841_984;
+
+/// Electron mass in unified atomic mass units
+const ELECTRON_MASS: f64 = 5.485_799_090_65e-4;
+
+/// Named series of lines ending on the same lower level
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Series {
+    Lyman = 1,
+    Balmer = 2,
+    Paschen = 3,
+    Brackett = 4,
+    Pfund = 5,
+    Humphreys = 6,
+}
+
+impl Series {
+    pub const ALL: [Self; 6] = [
+        Self::Lyman,
+        Self::Balmer,
+        Self::Paschen,
+        Self::Brackett,
+        Self::Pfund,
+        Self::Humphreys,
+    ];
+
+    /// Principal quantum number of the level the series ends on
+    pub const fn lower_level(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Series ending on level `n`, if it is named
+    pub fn from_lower_level(n: u8) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.lower_level() == n)
+    }
+
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Lyman => "Lyman",
+            Self::Balmer => "Balmer",
+            Self::Paschen => "Paschen",
+            Self::Brackett => "Brackett",
+            Self::Pfund => "Pfund",
+            Self::Humphreys => "Humphreys",
+        }
+    }
+}
+
+/// Emission of a photon by an electron falling between two levels
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Transition {
+    pub upper: u8,
+    pub lower: u8,
+    /// Energy of the photon in eV
+    pub energy: f64,
+}
+
+impl Transition {
+    /// Vacuum wavelength in nm
+    pub fn wavelength(&self) -> f64 {
+        PLANCK_SPEED_OF_LIGHT / self.energy
+    }
+
+    /// Wavenumber in cm⁻¹
+    pub fn wavenumber(&self) -> f64 {
+        1e7 / self.wavelength()
+    }
+
+    /// Frequency in Hz
+    pub fn frequency(&self) -> f64 {
+        299_792_458.0 / (self.wavelength() * 1e-9)
+    }
+
+    /// Named series the line belongs to
+    pub fn series(&self) -> Option<Series> {
+        Series::from_lower_level(self.lower)
+    }
+
+    /// Approximate sRGB colour of the line, if it is visible
+    ///
+    /// Uses a piecewise linear fit of the spectrum between 380 nm and 780 nm,
+    /// dimmed towards both ends where the eye is less sensitive.
+    pub fn colour(&self) -> Option<[u8; 3]> {
+        wavelength_to_srgb(self.wavelength())
+    }
+}
+
+/// Nucleus with a single electron
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hydrogenic {
+    /// Nuclear charge
+    pub z: u8,
+    /// Rydberg energy corrected for the reduced mass, in eV
+    pub rydberg_energy: f64,
+}
+
+impl Hydrogenic {
+    /// Single electron ion of the element, e.g. He⁺ for helium, or the atom
+    /// itself for hydrogen
+    pub fn new(element: Element) -> Self {
+        let z = element.atomic_number();
+        let nuclear_mass = element.atomic_mass() - z as f64 * ELECTRON_MASS;
+
+        Self {
+            z,
+            rydberg_energy: RYDBERG_ENERGY * nuclear_mass / (nuclear_mass + ELECTRON_MASS),
+        }
+    }
+
+    /// Hydrogen-like species of the ion, which must have exactly one electron
+    pub fn from_ion(ion: &Ion) -> Result<Self> {
+        match ion.atomic_number() as i16 - ion.oxidation_state as i16 {
+            1 => Ok(Self::new(ion.element)),
+            _ => Err(Error::NotHydrogenic(format!(
+                "{}{:+}",
+                ion.symbol(),
+                ion.oxidation_state
+            ))),
+        }
+    }
+
+    /// Energy of level `n` in eV, relative to the ionised species
+    ///
+    /// Fails for `n = 0`, which is not an energy level
+    ///
+    /// ```
+    /// use chemistru_elements::spectra::Hydrogenic;
+    /// use chemistru_elements::table::HYDROGEN;
+    ///
+    /// let hydrogen = Hydrogenic::new(HYDROGEN);
+    ///
+    /// assert!((hydrogen.ionisation_energy(1).unwrap() - 13.598).abs() < 0.001);
+    /// assert!(hydrogen.energy(0).is_err());
+    /// ```
+    pub fn energy(&self, n: u8) -> Result<f64> {
+        if n == 0 {
+            return Err(Error::InvalidQuantumNumber(n));
+        }
+
+        let z = self.z as f64;
+        let n = n as f64;
+
+        Ok(-z * z * self.rydberg_energy / (n * n))
+    }
+
+    /// Energy needed to remove the electron from level `n`, in eV
+    pub fn ionisation_energy(&self, n: u8) -> Result<f64> {
+        self.energy(n).map(|energy| -energy)
+    }
+
+    /// Emission from level `upper` to level `lower`
+    pub fn transition(&self, upper: u8, lower: u8) -> Result<Transition> {
+        if lower == 0 || upper <= lower {
+            return Err(Error::InvalidTransition { upper, lower });
+        }
+
+        Ok(Transition {
+            upper,
+            lower,
+            energy: self.energy(upper)? - self.energy(lower)?,
+        })
+    }
+
+    /// First `count` lines of a series, in order of decreasing wavelength
+    pub fn series(&self, series: Series, count: u8) -> Vec<Transition> {
+        let lower = series.lower_level();
+
+        (lower + 1..=lower.saturating_add(count))
+            .map(|upper| {
+                self.transition(upper, lower)
+                    .expect("Upper level is higher")
+            })
+            .collect()
+    }
+
+    /// Shortest wavelength of a series, as the upper level tends to infinity,
+    /// in nm
+    pub fn series_limit(&self, series: Series) -> f64 {
+        let limit = self
+            .ionisation_energy(series.lower_level())
+            .expect("Series ends on a level with n >= 1");
+
+        PLANCK_SPEED_OF_LIGHT / limit
+    }
+}
+
+/// Approximate sRGB colour of visible light with the given wavelength in nm
+pub fn wavelength_to_srgb(wavelength: f64) -> Option<[u8; 3]> {
+    let (r, g, b) = match wavelength {
+        w if (380.0..440.0).contains(&w) => ((440.0 - w) / 60.0, 0.0, 1.0),
+        w if (440.0..490.0).contains(&w) => (0.0, (w - 440.0) / 50.0, 1.0),
+        w if (490.0..510.0).contains(&w) => (0.0, 1.0, (510.0 - w) / 20.0),
+        w if (510.0..580.0).contains(&w) => ((w - 510.0) / 70.0, 1.0, 0.0),
+        w if (580.0..645.0).contains(&w) => (1.0, (645.0 - w) / 65.0, 0.0),
+        w if (645.0..=780.0).contains(&w) => (1.0, 0.0, 0.0),
+        _ => return None,
+    };
+
+    let intensity = match wavelength {
+        w if w < 420.0 => 0.3 + 0.7 * (w - 380.0) / 40.0,
+        w if w > 700.0 => 0.3 + 0.7 * (780.0 - w) / 80.0,
+        _ => 1.0,
+    };
+
+    let channel = |c: f64| (255.0 * (c * intensity).powf(0.8)).round() as u8;
+
+    Some([channel(r), channel(g), channel(b)])
+}
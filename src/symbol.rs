@@ -0,0 +1,104 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::element::Element;
+use crate::error::Error;
+
+macro_rules! element_symbols {
+    ($first:ident, $($rest:ident),+ $(,)?) => {
+        /// Symbol of every element in the periodic table
+        ///
+        /// Discriminants are the atomic numbers of the elements, allowing
+        /// exhaustive matching on elements without string comparisons.
+        ///
+        /// ```
+        /// use chemistru_elements::error::Error;
+        /// use chemistru_elements::symbol::ElementSymbol;
+        ///
+        /// let iron: ElementSymbol = "Fe".parse().unwrap();
+        ///
+        /// assert_eq!(iron, ElementSymbol::Fe);
+        /// assert_eq!(iron.to_string(), "Fe");
+        /// assert_eq!(ElementSymbol::try_from(26).unwrap(), iron);
+        ///
+        /// // Symbols are case sensitive
+        /// assert!(matches!("fe".parse::<ElementSymbol>(), Err(Error::UnknownSymbol(_))));
+        ///
+        /// assert!(matches!(ElementSymbol::try_from(0), Err(Error::InvalidAtomicNumber(0))));
+        /// assert!(matches!(ElementSymbol::try_from(119), Err(Error::InvalidAtomicNumber(119))));
+        /// ```
+        #[repr(u8)]
+        #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        pub enum ElementSymbol {
+            $first = 1,
+            $($rest),+
+        }
+
+        impl ElementSymbol {
+            /// Every symbol, ordered by atomic number
+            pub const ALL: [ElementSymbol; 118] = [Self::$first, $(Self::$rest),+];
+
+            /// Symbol as written in the periodic table, e.g. `Fe`
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::$first => stringify!($first),
+                    $(Self::$rest => stringify!($rest)),+
+                }
+            }
+        }
+    };
+}
+
+element_symbols! {
+    H, He, Li, Be, B, C, N, O, F, Ne, Na, Mg, Al, Si, P, S, Cl, Ar, K, Ca, Sc,
+    Ti, V, Cr, Mn, Fe, Co, Ni, Cu, Zn, Ga, Ge, As, Se, Br, Kr, Rb, Sr, Y, Zr,
+    Nb, Mo, Tc, Ru, Rh, Pd, Ag, Cd, In, Sn, Sb, Te, I, Xe, Cs, Ba, La, Ce, Pr,
+    Nd, Pm, Sm, Eu, Gd, Tb, Dy, Ho, Er, Tm, Yb, Lu, Hf, Ta, W, Re, Os, Ir, Pt,
+    Au, Hg, Tl, Pb, Bi, Po, At, Rn, Fr, Ra, Ac, Th, Pa, U, Np, Pu, Am, Cm, Bk,
+    Cf, Es, Fm, Md, No, Lr, Rf, Db, Sg, Bh, Hs, Mt, Ds, Rg, Cn, Nh, Fl, Mc, Lv,
+    Ts, Og,
+}
+
+impl ElementSymbol {
+    #[inline]
+    pub const fn atomic_number(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl fmt::Display for ElementSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ElementSymbol {
+    type Err = Error;
+
+    /// Parse a symbol, matching case exactly so that `Co` and `CO` differ
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Element::from_symbol(s)
+            .filter(|e| e.data().symbol == s)
+            .map(|e| e.symbol_enum())
+            .ok_or_else(|| Error::UnknownSymbol(s.to_string()))
+    }
+}
+
+impl TryFrom<u8> for ElementSymbol {
+    type Error = Error;
+
+    fn try_from(atomic_number: u8) -> Result<Self, Self::Error> {
+        let idx = atomic_number.wrapping_sub(1);
+
+        Self::ALL
+            .get(idx as usize)
+            .copied()
+            .ok_or(Error::InvalidAtomicNumber(atomic_number))
+    }
+}
+
+impl From<ElementSymbol> for Element {
+    fn from(symbol: ElementSymbol) -> Self {
+        Element::from_symbol_enum(symbol)
+    }
+}
@@ -0,0 +1,5939 @@
+// @generated by chemistru-elements from data/PeriodicTableJSON.json, do not edit by hand
+
+use chemistru_elements::phf;
+#[doc = r" Detailed information of every element, in the order they were read"]
+pub static ELEMENTS: [&chemistru_elements::inner::InnerElement; 118] = [
+    &chemistru_elements::inner::InnerElement {
+        name: "Hydrogen",
+        symbol: "H",
+        description: "Hydrogen is a diatomic nonmetal with the chemical symbol H and atomic number 1.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Hydrogen",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 1u8,
+            nucleon_number: 1u16,
+            atomic_mass: 1.008f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(13.81f64),
+            boiling_point: Some(20.28f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                ]]),
+            electron_configuration_semantic: "1s1",
+            ionisation_energies: &[1312f64],
+            shells: &[1u8],
+            electron_affinity: Some(72.75f64),
+            electronegativity: Some(2.2f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(0.00008988f64),
+            phase: "Gas",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "diatomic nonmetal",
+            period: 1u8,
+            xpos: 1u8,
+            ypos: 1u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("ffffff"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Helium",
+        symbol: "He",
+        description: "Helium is a noble gas with the chemical symbol He and atomic number 2.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Helium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 2u8,
+            nucleon_number: 4u16,
+            atomic_mass: 4.0026f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(0.95f64),
+            boiling_point: Some(4.22f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                ]]),
+            electron_configuration_semantic: "1s2",
+            ionisation_energies: &[2372.3f64],
+            shells: &[2u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(0.0001785f64),
+            phase: "Gas",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "noble gas",
+            period: 1u8,
+            xpos: 18u8,
+            ypos: 1u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("d9ffff"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Lithium",
+        symbol: "Li",
+        description: "Lithium is an alkali metal with the chemical symbol Li and atomic number 3.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Lithium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 3u8,
+            nucleon_number: 7u16,
+            atomic_mass: 7f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(453.65f64),
+            boiling_point: Some(1615f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [1u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[He] 2s1",
+            ionisation_energies: &[520.2f64],
+            shells: &[2u8, 1u8],
+            electron_affinity: Some(59.628f64),
+            electronegativity: Some(0.98f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(0.534f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "alkali metal",
+            period: 2u8,
+            xpos: 1u8,
+            ypos: 2u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("cc80ff"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Beryllium",
+        symbol: "Be",
+        description: "Beryllium is an alkaline earth metal with the chemical symbol Be and atomic number 4.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Beryllium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 4u8,
+            nucleon_number: 9u16,
+            atomic_mass: 9.012183f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1560f64),
+            boiling_point: Some(2744f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[He] 2s2",
+            ionisation_energies: &[899.5f64],
+            shells: &[2u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.57f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(1.85f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "alkaline earth metal",
+            period: 2u8,
+            xpos: 2u8,
+            ypos: 2u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("c2ff00"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Boron",
+        symbol: "B",
+        description: "Boron is a metalloid with the chemical symbol B and atomic number 5.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Boron",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 5u8,
+            nucleon_number: 11u16,
+            atomic_mass: 10.81f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(2348f64),
+            boiling_point: Some(4273f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 1u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[He] 2s2 2p1",
+            ionisation_energies: &[800.6f64],
+            shells: &[2u8, 3u8],
+            electron_affinity: Some(26.726f64),
+            electronegativity: Some(2.04f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(2.37f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "metalloid",
+            period: 2u8,
+            xpos: 13u8,
+            ypos: 2u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("ffb5b5"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Carbon",
+        symbol: "C",
+        description: "Carbon is a polyatomic nonmetal with the chemical symbol C and atomic number 6.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Carbon",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 6u8,
+            nucleon_number: 12u16,
+            atomic_mass: 12.011f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(3823f64),
+            boiling_point: Some(4098f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 2u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[He] 2s2 2p2",
+            ionisation_energies: &[1086.4f64],
+            shells: &[2u8, 4u8],
+            electron_affinity: Some(121.861f64),
+            electronegativity: Some(2.55f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(2.267f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "polyatomic nonmetal",
+            period: 2u8,
+            xpos: 14u8,
+            ypos: 2u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("909090"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Nitrogen",
+        symbol: "N",
+        description: "Nitrogen is a diatomic nonmetal with the chemical symbol N and atomic number 7.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Nitrogen",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 7u8,
+            nucleon_number: 14u16,
+            atomic_mass: 14.007f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(63.15f64),
+            boiling_point: Some(77.36f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 3u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[He] 2s2 2p3",
+            ionisation_energies: &[1402.3f64],
+            shells: &[2u8, 5u8],
+            electron_affinity: None,
+            electronegativity: Some(3.04f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(0.0012506f64),
+            phase: "Gas",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "diatomic nonmetal",
+            period: 2u8,
+            xpos: 15u8,
+            ypos: 2u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("3050f8"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Oxygen",
+        symbol: "O",
+        description: "Oxygen is a diatomic nonmetal with the chemical symbol O and atomic number 8.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Oxygen",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 8u8,
+            nucleon_number: 16u16,
+            atomic_mass: 15.999f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(54.36f64),
+            boiling_point: Some(90.2f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 4u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[He] 2s2 2p4",
+            ionisation_energies: &[1313.9f64],
+            shells: &[2u8, 6u8],
+            electron_affinity: Some(140.965f64),
+            electronegativity: Some(3.44f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(0.001429f64),
+            phase: "Gas",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "diatomic nonmetal",
+            period: 2u8,
+            xpos: 16u8,
+            ypos: 2u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("ff0d0d"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Fluorine",
+        symbol: "F",
+        description: "Fluorine is a halogen with the chemical symbol F and atomic number 9.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Fluorine",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 9u8,
+            nucleon_number: 19u16,
+            atomic_mass: 18.99840316f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(53.53f64),
+            boiling_point: Some(85.03f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 5u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[He] 2s2 2p5",
+            ionisation_energies: &[1681.1f64],
+            shells: &[2u8, 7u8],
+            electron_affinity: Some(322.165f64),
+            electronegativity: Some(3.98f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(0.001696f64),
+            phase: "Gas",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "halogen",
+            period: 2u8,
+            xpos: 17u8,
+            ypos: 2u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("90e050"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Neon",
+        symbol: "Ne",
+        description: "Neon is a noble gas with the chemical symbol Ne and atomic number 10.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Neon",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 10u8,
+            nucleon_number: 20u16,
+            atomic_mass: 20.18f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(24.56f64),
+            boiling_point: Some(27.07f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[He] 2s2 2p6",
+            ionisation_energies: &[2080.7f64],
+            shells: &[2u8, 8u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(0.0008999f64),
+            phase: "Gas",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "noble gas",
+            period: 2u8,
+            xpos: 18u8,
+            ypos: 2u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("b3e3f5"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Sodium",
+        symbol: "Na",
+        description: "Sodium is an alkali metal with the chemical symbol Na and atomic number 11.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Sodium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 11u8,
+            nucleon_number: 23u16,
+            atomic_mass: 22.9897693f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(370.95f64),
+            boiling_point: Some(1156f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [1u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ne] 3s1",
+            ionisation_energies: &[495.8f64],
+            shells: &[2u8, 8u8, 1u8],
+            electron_affinity: Some(52.874f64),
+            electronegativity: Some(0.93f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(0.97f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "alkali metal",
+            period: 3u8,
+            xpos: 1u8,
+            ypos: 3u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("ab5cf2"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Magnesium",
+        symbol: "Mg",
+        description: "Magnesium is an alkaline earth metal with the chemical symbol Mg and atomic number 12.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Magnesium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 12u8,
+            nucleon_number: 24u16,
+            atomic_mass: 24.305f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(923f64),
+            boiling_point: Some(1363f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ne] 3s2",
+            ionisation_energies: &[737.7f64],
+            shells: &[2u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.31f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(1.74f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "alkaline earth metal",
+            period: 3u8,
+            xpos: 2u8,
+            ypos: 3u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("8aff00"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Aluminium",
+        symbol: "Al",
+        description: "Aluminium is a post-transition metal with the chemical symbol Al and atomic number 13.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Aluminium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 13u8,
+            nucleon_number: 27u16,
+            atomic_mass: 26.981538f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(933.437f64),
+            boiling_point: Some(2792f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 1u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ne] 3s2 3p1",
+            ionisation_energies: &[577.6f64],
+            shells: &[2u8, 8u8, 3u8],
+            electron_affinity: Some(42.55f64),
+            electronegativity: Some(1.61f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(2.7f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "post-transition metal",
+            period: 3u8,
+            xpos: 13u8,
+            ypos: 3u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("bfa6a6"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Silicon",
+        symbol: "Si",
+        description: "Silicon is a metalloid with the chemical symbol Si and atomic number 14.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Silicon",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 14u8,
+            nucleon_number: 28u16,
+            atomic_mass: 28.085f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1687f64),
+            boiling_point: Some(3538f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 2u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ne] 3s2 3p2",
+            ionisation_energies: &[786.5f64],
+            shells: &[2u8, 8u8, 4u8],
+            electron_affinity: Some(133.632f64),
+            electronegativity: Some(1.9f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(2.3296f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "metalloid",
+            period: 3u8,
+            xpos: 14u8,
+            ypos: 3u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("f0c8a0"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Phosphorus",
+        symbol: "P",
+        description: "Phosphorus is a polyatomic nonmetal with the chemical symbol P and atomic number 15.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Phosphorus",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 15u8,
+            nucleon_number: 31u16,
+            atomic_mass: 30.973762f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(317.3f64),
+            boiling_point: Some(553.65f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 3u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ne] 3s2 3p3",
+            ionisation_energies: &[1011.8f64],
+            shells: &[2u8, 8u8, 5u8],
+            electron_affinity: Some(71.978f64),
+            electronegativity: Some(2.19f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(1.82f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "polyatomic nonmetal",
+            period: 3u8,
+            xpos: 15u8,
+            ypos: 3u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("ff8000"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Sulfur",
+        symbol: "S",
+        description: "Sulfur is a polyatomic nonmetal with the chemical symbol S and atomic number 16.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Sulfur",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 16u8,
+            nucleon_number: 32u16,
+            atomic_mass: 32.07f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(388.36f64),
+            boiling_point: Some(717.75f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 4u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ne] 3s2 3p4",
+            ionisation_energies: &[999.6f64],
+            shells: &[2u8, 8u8, 6u8],
+            electron_affinity: Some(200.4f64),
+            electronegativity: Some(2.58f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(2.067f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "polyatomic nonmetal",
+            period: 3u8,
+            xpos: 16u8,
+            ypos: 3u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("ffff30"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Chlorine",
+        symbol: "Cl",
+        description: "Chlorine is a halogen with the chemical symbol Cl and atomic number 17.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Chlorine",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 17u8,
+            nucleon_number: 35u16,
+            atomic_mass: 35.45f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(171.65f64),
+            boiling_point: Some(239.11f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 5u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ne] 3s2 3p5",
+            ionisation_energies: &[1251.2f64],
+            shells: &[2u8, 8u8, 7u8],
+            electron_affinity: Some(348.987f64),
+            electronegativity: Some(3.16f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(0.003214f64),
+            phase: "Gas",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "halogen",
+            period: 3u8,
+            xpos: 17u8,
+            ypos: 3u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("1ff01f"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Argon",
+        symbol: "Ar",
+        description: "Argon is a noble gas with the chemical symbol Ar and atomic number 18.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Argon",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 18u8,
+            nucleon_number: 40u16,
+            atomic_mass: 39.9f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(83.8f64),
+            boiling_point: Some(87.3f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ne] 3s2 3p6",
+            ionisation_energies: &[1520.6f64],
+            shells: &[2u8, 8u8, 8u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(0.0017837f64),
+            phase: "Gas",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "noble gas",
+            period: 3u8,
+            xpos: 18u8,
+            ypos: 3u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("80d1e3"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Potassium",
+        symbol: "K",
+        description: "Potassium is an alkali metal with the chemical symbol K and atomic number 19.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Potassium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 19u8,
+            nucleon_number: 39u16,
+            atomic_mass: 39.098f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(336.53f64),
+            boiling_point: Some(1032f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [1u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 4s1",
+            ionisation_energies: &[418.8f64],
+            shells: &[2u8, 8u8, 8u8, 1u8],
+            electron_affinity: Some(48.339f64),
+            electronegativity: Some(0.82f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(0.89f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "alkali metal",
+            period: 4u8,
+            xpos: 1u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("8f40d4"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Calcium",
+        symbol: "Ca",
+        description: "Calcium is an alkaline earth metal with the chemical symbol Ca and atomic number 20.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Calcium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 20u8,
+            nucleon_number: 40u16,
+            atomic_mass: 40.08f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1115f64),
+            boiling_point: Some(1757f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 4s2",
+            ionisation_energies: &[589.8f64],
+            shells: &[2u8, 8u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(1.54f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "alkaline earth metal",
+            period: 4u8,
+            xpos: 2u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("3dff00"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Scandium",
+        symbol: "Sc",
+        description: "Scandium is a transition metal with the chemical symbol Sc and atomic number 21.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Scandium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 21u8,
+            nucleon_number: 45u16,
+            atomic_mass: 44.95591f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1814f64),
+            boiling_point: Some(3109f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 1u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d1 4s2",
+            ionisation_energies: &[633f64],
+            shells: &[2u8, 8u8, 9u8, 2u8],
+            electron_affinity: Some(18.139f64),
+            electronegativity: Some(1.36f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(2.99f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 4u8,
+            xpos: 3u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("e6e6e6"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Titanium",
+        symbol: "Ti",
+        description: "Titanium is a transition metal with the chemical symbol Ti and atomic number 22.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Titanium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 22u8,
+            nucleon_number: 48u16,
+            atomic_mass: 47.87f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1941f64),
+            boiling_point: Some(3560f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 2u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d2 4s2",
+            ionisation_energies: &[658.8f64],
+            shells: &[2u8, 8u8, 10u8, 2u8],
+            electron_affinity: Some(7.622f64),
+            electronegativity: Some(1.54f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(4.5f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 4u8,
+            xpos: 4u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("bfc2c7"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Vanadium",
+        symbol: "V",
+        description: "Vanadium is a transition metal with the chemical symbol V and atomic number 23.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Vanadium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 23u8,
+            nucleon_number: 51u16,
+            atomic_mass: 50.941f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(2183f64),
+            boiling_point: Some(3680f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 3u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d3 4s2",
+            ionisation_energies: &[650.9f64],
+            shells: &[2u8, 8u8, 11u8, 2u8],
+            electron_affinity: Some(50.655f64),
+            electronegativity: Some(1.63f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(6f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 4u8,
+            xpos: 5u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("a6a6ab"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Chromium",
+        symbol: "Cr",
+        description: "Chromium is a transition metal with the chemical symbol Cr and atomic number 24.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Chromium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 24u8,
+            nucleon_number: 52u16,
+            atomic_mass: 51.996f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(2180f64),
+            boiling_point: Some(2944f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 5u8, 0u8, 0u8, 0u8],
+                    [1u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d5 4s1",
+            ionisation_energies: &[652.9f64],
+            shells: &[2u8, 8u8, 13u8, 1u8],
+            electron_affinity: Some(64.259f64),
+            electronegativity: Some(1.66f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(7.15f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 4u8,
+            xpos: 6u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("8a99c7"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Manganese",
+        symbol: "Mn",
+        description: "Manganese is a transition metal with the chemical symbol Mn and atomic number 25.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Manganese",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 25u8,
+            nucleon_number: 55u16,
+            atomic_mass: 54.93804f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1519f64),
+            boiling_point: Some(2334f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 5u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d5 4s2",
+            ionisation_energies: &[717.3f64],
+            shells: &[2u8, 8u8, 13u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.55f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(7.3f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 4u8,
+            xpos: 7u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("9c7ac7"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Iron",
+        symbol: "Fe",
+        description: "Iron is a transition metal with the chemical symbol Fe and atomic number 26.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Iron",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 26u8,
+            nucleon_number: 56u16,
+            atomic_mass: 55.84f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1811f64),
+            boiling_point: Some(3134f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 6u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d6 4s2",
+            ionisation_energies: &[762.4f64],
+            shells: &[2u8, 8u8, 14u8, 2u8],
+            electron_affinity: Some(15.727f64),
+            electronegativity: Some(1.83f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(7.874f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 4u8,
+            xpos: 8u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("e06633"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Cobalt",
+        symbol: "Co",
+        description: "Cobalt is a transition metal with the chemical symbol Co and atomic number 27.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Cobalt",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 27u8,
+            nucleon_number: 59u16,
+            atomic_mass: 58.93319f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1768f64),
+            boiling_point: Some(3200f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 7u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d7 4s2",
+            ionisation_energies: &[760.4f64],
+            shells: &[2u8, 8u8, 15u8, 2u8],
+            electron_affinity: Some(63.777f64),
+            electronegativity: Some(1.88f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(8.86f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 4u8,
+            xpos: 9u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("f090a0"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Nickel",
+        symbol: "Ni",
+        description: "Nickel is a transition metal with the chemical symbol Ni and atomic number 28.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Nickel",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 28u8,
+            nucleon_number: 59u16,
+            atomic_mass: 58.693f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1728f64),
+            boiling_point: Some(3186f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 8u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d8 4s2",
+            ionisation_energies: &[737.1f64],
+            shells: &[2u8, 8u8, 16u8, 2u8],
+            electron_affinity: Some(111.537f64),
+            electronegativity: Some(1.91f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(8.912f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 4u8,
+            xpos: 10u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("50d050"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Copper",
+        symbol: "Cu",
+        description: "Copper is a transition metal with the chemical symbol Cu and atomic number 29.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Copper",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 29u8,
+            nucleon_number: 64u16,
+            atomic_mass: 63.55f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1357.77f64),
+            boiling_point: Some(2835f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [1u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d10 4s1",
+            ionisation_energies: &[745.4f64],
+            shells: &[2u8, 8u8, 18u8, 1u8],
+            electron_affinity: Some(118.484f64),
+            electronegativity: Some(1.9f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(8.933f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 4u8,
+            xpos: 11u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("c88033"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Zinc",
+        symbol: "Zn",
+        description: "Zinc is a transition metal with the chemical symbol Zn and atomic number 30.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Zinc",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 30u8,
+            nucleon_number: 65u16,
+            atomic_mass: 65.4f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(692.68f64),
+            boiling_point: Some(1180f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d10 4s2",
+            ionisation_energies: &[906.4f64],
+            shells: &[2u8, 8u8, 18u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.65f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(7.134f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 4u8,
+            xpos: 12u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("7d80b0"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Gallium",
+        symbol: "Ga",
+        description: "Gallium is a post-transition metal with the chemical symbol Ga and atomic number 31.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Gallium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 31u8,
+            nucleon_number: 70u16,
+            atomic_mass: 69.72f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(302.91f64),
+            boiling_point: Some(2477f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 1u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d10 4s2 4p1",
+            ionisation_energies: &[578.8f64],
+            shells: &[2u8, 8u8, 18u8, 3u8],
+            electron_affinity: Some(28.946f64),
+            electronegativity: Some(1.81f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(5.91f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "post-transition metal",
+            period: 4u8,
+            xpos: 13u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("c28f8f"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Germanium",
+        symbol: "Ge",
+        description: "Germanium is a metalloid with the chemical symbol Ge and atomic number 32.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Germanium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 32u8,
+            nucleon_number: 73u16,
+            atomic_mass: 72.63f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1211.4f64),
+            boiling_point: Some(3106f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 2u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d10 4s2 4p2",
+            ionisation_energies: &[762.2f64],
+            shells: &[2u8, 8u8, 18u8, 4u8],
+            electron_affinity: Some(130.255f64),
+            electronegativity: Some(2.01f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(5.323f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "metalloid",
+            period: 4u8,
+            xpos: 14u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("668f8f"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Arsenic",
+        symbol: "As",
+        description: "Arsenic is a metalloid with the chemical symbol As and atomic number 33.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Arsenic",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 33u8,
+            nucleon_number: 75u16,
+            atomic_mass: 74.92159f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1090f64),
+            boiling_point: Some(887f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 3u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d10 4s2 4p3",
+            ionisation_energies: &[947f64],
+            shells: &[2u8, 8u8, 18u8, 5u8],
+            electron_affinity: Some(78.153f64),
+            electronegativity: Some(2.18f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(5.776f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "metalloid",
+            period: 4u8,
+            xpos: 15u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("bd80e3"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Selenium",
+        symbol: "Se",
+        description: "Selenium is a polyatomic nonmetal with the chemical symbol Se and atomic number 34.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Selenium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 34u8,
+            nucleon_number: 79u16,
+            atomic_mass: 78.97f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(493.65f64),
+            boiling_point: Some(958f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 4u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d10 4s2 4p4",
+            ionisation_energies: &[940.9f64],
+            shells: &[2u8, 8u8, 18u8, 6u8],
+            electron_affinity: Some(194.997f64),
+            electronegativity: Some(2.55f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(4.809f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "polyatomic nonmetal",
+            period: 4u8,
+            xpos: 16u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("ffa100"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Bromine",
+        symbol: "Br",
+        description: "Bromine is a halogen with the chemical symbol Br and atomic number 35.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Bromine",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 35u8,
+            nucleon_number: 80u16,
+            atomic_mass: 79.9f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(265.95f64),
+            boiling_point: Some(331.95f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 5u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d10 4s2 4p5",
+            ionisation_energies: &[1139.9f64],
+            shells: &[2u8, 8u8, 18u8, 7u8],
+            electron_affinity: Some(324.673f64),
+            electronegativity: Some(2.96f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(3.11f64),
+            phase: "Liquid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "halogen",
+            period: 4u8,
+            xpos: 17u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("a62929"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Krypton",
+        symbol: "Kr",
+        description: "Krypton is a noble gas with the chemical symbol Kr and atomic number 36.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Krypton",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 36u8,
+            nucleon_number: 84u16,
+            atomic_mass: 83.8f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(115.79f64),
+            boiling_point: Some(119.93f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Ar] 3d10 4s2 4p6",
+            ionisation_energies: &[1350.8f64],
+            shells: &[2u8, 8u8, 18u8, 8u8],
+            electron_affinity: None,
+            electronegativity: Some(3f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(0.003733f64),
+            phase: "Gas",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "noble gas",
+            period: 4u8,
+            xpos: 18u8,
+            ypos: 4u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("5cb8d1"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Rubidium",
+        symbol: "Rb",
+        description: "Rubidium is an alkali metal with the chemical symbol Rb and atomic number 37.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Rubidium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 37u8,
+            nucleon_number: 85u16,
+            atomic_mass: 85.468f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(312.46f64),
+            boiling_point: Some(961f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [1u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 5s1",
+            ionisation_energies: &[403f64],
+            shells: &[2u8, 8u8, 18u8, 8u8, 1u8],
+            electron_affinity: Some(45.155f64),
+            electronegativity: Some(0.82f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(1.53f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "alkali metal",
+            period: 5u8,
+            xpos: 1u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("702eb0"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Strontium",
+        symbol: "Sr",
+        description: "Strontium is an alkaline earth metal with the chemical symbol Sr and atomic number 38.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Strontium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 38u8,
+            nucleon_number: 88u16,
+            atomic_mass: 87.6f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1050f64),
+            boiling_point: Some(1655f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 5s2",
+            ionisation_energies: &[549.5f64],
+            shells: &[2u8, 8u8, 18u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(0.95f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(2.64f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "alkaline earth metal",
+            period: 5u8,
+            xpos: 2u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("00ff00"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Yttrium",
+        symbol: "Y",
+        description: "Yttrium is a transition metal with the chemical symbol Y and atomic number 39.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Yttrium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 39u8,
+            nucleon_number: 89u16,
+            atomic_mass: 88.9058f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1795f64),
+            boiling_point: Some(3618f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 1u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d1 5s2",
+            ionisation_energies: &[599.8f64],
+            shells: &[2u8, 8u8, 18u8, 9u8, 2u8],
+            electron_affinity: Some(29.621f64),
+            electronegativity: Some(1.22f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(4.47f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 5u8,
+            xpos: 3u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("94ffff"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Zirconium",
+        symbol: "Zr",
+        description: "Zirconium is a transition metal with the chemical symbol Zr and atomic number 40.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Zirconium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 40u8,
+            nucleon_number: 91u16,
+            atomic_mass: 91.22f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(2128f64),
+            boiling_point: Some(4682f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 2u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d2 5s2",
+            ionisation_energies: &[640.1f64],
+            shells: &[2u8, 8u8, 18u8, 10u8, 2u8],
+            electron_affinity: Some(41.103f64),
+            electronegativity: Some(1.33f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(6.52f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 5u8,
+            xpos: 4u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("94e0e0"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Niobium",
+        symbol: "Nb",
+        description: "Niobium is a transition metal with the chemical symbol Nb and atomic number 41.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Niobium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 41u8,
+            nucleon_number: 93u16,
+            atomic_mass: 92.9064f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(2750f64),
+            boiling_point: Some(5017f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 4u8, 0u8, 0u8, 0u8],
+                    [1u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d4 5s1",
+            ionisation_energies: &[652.1f64],
+            shells: &[2u8, 8u8, 18u8, 12u8, 1u8],
+            electron_affinity: Some(86.161f64),
+            electronegativity: Some(1.6f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(8.57f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 5u8,
+            xpos: 5u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("73c2c9"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Molybdenum",
+        symbol: "Mo",
+        description: "Molybdenum is a transition metal with the chemical symbol Mo and atomic number 42.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Molybdenum",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 42u8,
+            nucleon_number: 96u16,
+            atomic_mass: 96f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(2896f64),
+            boiling_point: Some(4912f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 5u8, 0u8, 0u8, 0u8],
+                    [1u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d5 5s1",
+            ionisation_energies: &[684.3f64],
+            shells: &[2u8, 8u8, 18u8, 13u8, 1u8],
+            electron_affinity: Some(71.978f64),
+            electronegativity: Some(2.16f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(10.2f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 5u8,
+            xpos: 6u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("54b5b5"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Technetium",
+        symbol: "Tc",
+        description: "Technetium is a transition metal with the chemical symbol Tc and atomic number 43.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Technetium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 43u8,
+            nucleon_number: 98u16,
+            atomic_mass: 97.90721f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(2430f64),
+            boiling_point: Some(4538f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 5u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d5 5s2",
+            ionisation_energies: &[702.4f64],
+            shells: &[2u8, 8u8, 18u8, 13u8, 2u8],
+            electron_affinity: Some(53.067f64),
+            electronegativity: Some(1.9f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(11f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 5u8,
+            xpos: 7u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("3b9e9e"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Ruthenium",
+        symbol: "Ru",
+        description: "Ruthenium is a transition metal with the chemical symbol Ru and atomic number 44.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Ruthenium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 44u8,
+            nucleon_number: 101u16,
+            atomic_mass: 101.1f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(2607f64),
+            boiling_point: Some(4423f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 7u8, 0u8, 0u8, 0u8],
+                    [1u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d7 5s1",
+            ionisation_energies: &[710.2f64],
+            shells: &[2u8, 8u8, 18u8, 15u8, 1u8],
+            electron_affinity: Some(101.31f64),
+            electronegativity: Some(2.2f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(12.1f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 5u8,
+            xpos: 8u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("248f8f"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Rhodium",
+        symbol: "Rh",
+        description: "Rhodium is a transition metal with the chemical symbol Rh and atomic number 45.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Rhodium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 45u8,
+            nucleon_number: 103u16,
+            atomic_mass: 102.9055f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(2237f64),
+            boiling_point: Some(3968f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 8u8, 0u8, 0u8, 0u8],
+                    [1u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d8 5s1",
+            ionisation_energies: &[719.7f64],
+            shells: &[2u8, 8u8, 18u8, 16u8, 1u8],
+            electron_affinity: Some(109.704f64),
+            electronegativity: Some(2.28f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(12.4f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 5u8,
+            xpos: 9u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("0a7d8c"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Palladium",
+        symbol: "Pd",
+        description: "Palladium is a transition metal with the chemical symbol Pd and atomic number 46.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Palladium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 46u8,
+            nucleon_number: 106u16,
+            atomic_mass: 106.4f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1828.05f64),
+            boiling_point: Some(3236f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d10",
+            ionisation_energies: &[804.4f64],
+            shells: &[2u8, 8u8, 18u8, 18u8],
+            electron_affinity: Some(53.742f64),
+            electronegativity: Some(2.2f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(12f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 5u8,
+            xpos: 10u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("006985"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Silver",
+        symbol: "Ag",
+        description: "Silver is a transition metal with the chemical symbol Ag and atomic number 47.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Silver",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 47u8,
+            nucleon_number: 108u16,
+            atomic_mass: 107.868f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1234.93f64),
+            boiling_point: Some(2435f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [1u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d10 5s1",
+            ionisation_energies: &[731f64],
+            shells: &[2u8, 8u8, 18u8, 18u8, 1u8],
+            electron_affinity: Some(125.624f64),
+            electronegativity: Some(1.93f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(10.501f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 5u8,
+            xpos: 11u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("c0c0c0"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Cadmium",
+        symbol: "Cd",
+        description: "Cadmium is a transition metal with the chemical symbol Cd and atomic number 48.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Cadmium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 48u8,
+            nucleon_number: 112u16,
+            atomic_mass: 112.41f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(594.22f64),
+            boiling_point: Some(1040f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d10 5s2",
+            ionisation_energies: &[867.8f64],
+            shells: &[2u8, 8u8, 18u8, 18u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.69f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(8.69f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 5u8,
+            xpos: 12u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("ffd98f"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Indium",
+        symbol: "In",
+        description: "Indium is a post-transition metal with the chemical symbol In and atomic number 49.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Indium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 49u8,
+            nucleon_number: 115u16,
+            atomic_mass: 114.82f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(429.75f64),
+            boiling_point: Some(2345f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 1u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d10 5s2 5p1",
+            ionisation_energies: &[558.3f64],
+            shells: &[2u8, 8u8, 18u8, 18u8, 3u8],
+            electron_affinity: Some(28.946f64),
+            electronegativity: Some(1.78f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(7.31f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "post-transition metal",
+            period: 5u8,
+            xpos: 13u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("a67573"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Tin",
+        symbol: "Sn",
+        description: "Tin is a post-transition metal with the chemical symbol Sn and atomic number 50.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Tin",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 50u8,
+            nucleon_number: 119u16,
+            atomic_mass: 118.71f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(505.08f64),
+            boiling_point: Some(2875f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 2u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d10 5s2 5p2",
+            ionisation_energies: &[708.6f64],
+            shells: &[2u8, 8u8, 18u8, 18u8, 4u8],
+            electron_affinity: Some(115.782f64),
+            electronegativity: Some(1.96f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(7.287f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "post-transition metal",
+            period: 5u8,
+            xpos: 14u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("668080"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Antimony",
+        symbol: "Sb",
+        description: "Antimony is a metalloid with the chemical symbol Sb and atomic number 51.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Antimony",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 51u8,
+            nucleon_number: 122u16,
+            atomic_mass: 121.76f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(903.78f64),
+            boiling_point: Some(1860f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 3u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d10 5s2 5p3",
+            ionisation_energies: &[833.6f64],
+            shells: &[2u8, 8u8, 18u8, 18u8, 5u8],
+            electron_affinity: Some(103.239f64),
+            electronegativity: Some(2.05f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(6.685f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "metalloid",
+            period: 5u8,
+            xpos: 15u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("9e63b5"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Tellurium",
+        symbol: "Te",
+        description: "Tellurium is a metalloid with the chemical symbol Te and atomic number 52.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Tellurium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 52u8,
+            nucleon_number: 128u16,
+            atomic_mass: 127.6f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(722.66f64),
+            boiling_point: Some(1261f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 4u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d10 5s2 5p4",
+            ionisation_energies: &[869.3f64],
+            shells: &[2u8, 8u8, 18u8, 18u8, 6u8],
+            electron_affinity: Some(190.173f64),
+            electronegativity: Some(2.1f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(6.232f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "metalloid",
+            period: 5u8,
+            xpos: 16u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("d47a00"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Iodine",
+        symbol: "I",
+        description: "Iodine is a halogen with the chemical symbol I and atomic number 53.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Iodine",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 53u8,
+            nucleon_number: 127u16,
+            atomic_mass: 126.9045f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(386.85f64),
+            boiling_point: Some(457.55f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 5u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d10 5s2 5p5",
+            ionisation_energies: &[1008.4f64],
+            shells: &[2u8, 8u8, 18u8, 18u8, 7u8],
+            electron_affinity: Some(295.149f64),
+            electronegativity: Some(2.66f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(4.93f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "halogen",
+            period: 5u8,
+            xpos: 17u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("940094"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Xenon",
+        symbol: "Xe",
+        description: "Xenon is a noble gas with the chemical symbol Xe and atomic number 54.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Xenon",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 54u8,
+            nucleon_number: 131u16,
+            atomic_mass: 131.29f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(161.36f64),
+            boiling_point: Some(165.03f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Kr] 4d10 5s2 5p6",
+            ionisation_energies: &[1170.4f64],
+            shells: &[2u8, 8u8, 18u8, 18u8, 8u8],
+            electron_affinity: None,
+            electronegativity: Some(2.6f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(0.005887f64),
+            phase: "Gas",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "noble gas",
+            period: 5u8,
+            xpos: 18u8,
+            ypos: 5u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("429eb0"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Caesium",
+        symbol: "Cs",
+        description: "Caesium is an alkali metal with the chemical symbol Cs and atomic number 55.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Caesium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 55u8,
+            nucleon_number: 133u16,
+            atomic_mass: 132.905452f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(301.59f64),
+            boiling_point: Some(944f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [1u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 6s1",
+            ionisation_energies: &[375.7f64],
+            shells: &[2u8, 8u8, 18u8, 18u8, 8u8, 1u8],
+            electron_affinity: Some(45.541f64),
+            electronegativity: Some(0.79f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(1.93f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "alkali metal",
+            period: 6u8,
+            xpos: 1u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("57178f"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Barium",
+        symbol: "Ba",
+        description: "Barium is an alkaline earth metal with the chemical symbol Ba and atomic number 56.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Barium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 56u8,
+            nucleon_number: 137u16,
+            atomic_mass: 137.33f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1000f64),
+            boiling_point: Some(2170f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 6s2",
+            ionisation_energies: &[502.9f64],
+            shells: &[2u8, 8u8, 18u8, 18u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(0.89f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(3.62f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "alkaline earth metal",
+            period: 6u8,
+            xpos: 2u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("00c900"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Lanthanum",
+        symbol: "La",
+        description: "Lanthanum is a lanthanide with the chemical symbol La and atomic number 57.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Lanthanum",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 57u8,
+            nucleon_number: 139u16,
+            atomic_mass: 138.9055f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1191f64),
+            boiling_point: Some(3737f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 1u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 5d1 6s2",
+            ionisation_energies: &[538.1f64],
+            shells: &[2u8, 8u8, 18u8, 18u8, 9u8, 2u8],
+            electron_affinity: Some(48.243f64),
+            electronegativity: Some(1.1f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(6.15f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "lanthanide",
+            period: 6u8,
+            xpos: 3u8,
+            ypos: 9u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("70d4ff"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Cerium",
+        symbol: "Ce",
+        description: "Cerium is a lanthanide with the chemical symbol Ce and atomic number 58.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Cerium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 58u8,
+            nucleon_number: 140u16,
+            atomic_mass: 140.12f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1071f64),
+            boiling_point: Some(3697f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 1u8, 0u8, 0u8],
+                    [2u8, 6u8, 1u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f1 5d1 6s2",
+            ionisation_energies: &[534.4f64],
+            shells: &[2u8, 8u8, 18u8, 19u8, 9u8, 2u8],
+            electron_affinity: Some(48.243f64),
+            electronegativity: Some(1.12f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(6.77f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "lanthanide",
+            period: 6u8,
+            xpos: 4u8,
+            ypos: 9u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("ffffc7"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Praseodymium",
+        symbol: "Pr",
+        description: "Praseodymium is a lanthanide with the chemical symbol Pr and atomic number 59.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Praseodymium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 59u8,
+            nucleon_number: 141u16,
+            atomic_mass: 140.9077f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1204f64),
+            boiling_point: Some(3793f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 3u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f3 6s2",
+            ionisation_energies: &[527.2f64],
+            shells: &[2u8, 8u8, 18u8, 21u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.13f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(6.77f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "lanthanide",
+            period: 6u8,
+            xpos: 5u8,
+            ypos: 9u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("d9ffc7"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Neodymium",
+        symbol: "Nd",
+        description: "Neodymium is a lanthanide with the chemical symbol Nd and atomic number 60.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Neodymium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 60u8,
+            nucleon_number: 144u16,
+            atomic_mass: 144.24f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1294f64),
+            boiling_point: Some(3347f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 4u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f4 6s2",
+            ionisation_energies: &[533.1f64],
+            shells: &[2u8, 8u8, 18u8, 22u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.14f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(7.01f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "lanthanide",
+            period: 6u8,
+            xpos: 6u8,
+            ypos: 9u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("c7ffc7"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Promethium",
+        symbol: "Pm",
+        description: "Promethium is a lanthanide with the chemical symbol Pm and atomic number 61.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Promethium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 61u8,
+            nucleon_number: 145u16,
+            atomic_mass: 144.91276f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1315f64),
+            boiling_point: Some(3273f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 5u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f5 6s2",
+            ionisation_energies: &[535.5f64],
+            shells: &[2u8, 8u8, 18u8, 23u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(7.26f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "lanthanide",
+            period: 6u8,
+            xpos: 7u8,
+            ypos: 9u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("a3ffc7"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Samarium",
+        symbol: "Sm",
+        description: "Samarium is a lanthanide with the chemical symbol Sm and atomic number 62.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Samarium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 62u8,
+            nucleon_number: 150u16,
+            atomic_mass: 150.4f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1347f64),
+            boiling_point: Some(2067f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 6u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f6 6s2",
+            ionisation_energies: &[544.6f64],
+            shells: &[2u8, 8u8, 18u8, 24u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.17f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(7.52f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "lanthanide",
+            period: 6u8,
+            xpos: 8u8,
+            ypos: 9u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("8fffc7"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Europium",
+        symbol: "Eu",
+        description: "Europium is a lanthanide with the chemical symbol Eu and atomic number 63.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Europium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 63u8,
+            nucleon_number: 152u16,
+            atomic_mass: 151.96f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1095f64),
+            boiling_point: Some(1802f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 7u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f7 6s2",
+            ionisation_energies: &[547.1f64],
+            shells: &[2u8, 8u8, 18u8, 25u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(5.24f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "lanthanide",
+            period: 6u8,
+            xpos: 9u8,
+            ypos: 9u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("61ffc7"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Gadolinium",
+        symbol: "Gd",
+        description: "Gadolinium is a lanthanide with the chemical symbol Gd and atomic number 64.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Gadolinium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 64u8,
+            nucleon_number: 157u16,
+            atomic_mass: 157.2f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1586f64),
+            boiling_point: Some(3546f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 7u8, 0u8, 0u8],
+                    [2u8, 6u8, 1u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f7 5d1 6s2",
+            ionisation_energies: &[593.4f64],
+            shells: &[2u8, 8u8, 18u8, 25u8, 9u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.2f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(7.9f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "lanthanide",
+            period: 6u8,
+            xpos: 10u8,
+            ypos: 9u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("45ffc7"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Terbium",
+        symbol: "Tb",
+        description: "Terbium is a lanthanide with the chemical symbol Tb and atomic number 65.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Terbium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 65u8,
+            nucleon_number: 159u16,
+            atomic_mass: 158.92535f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1629f64),
+            boiling_point: Some(3503f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 9u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f9 6s2",
+            ionisation_energies: &[565.8f64],
+            shells: &[2u8, 8u8, 18u8, 27u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(8.23f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "lanthanide",
+            period: 6u8,
+            xpos: 11u8,
+            ypos: 9u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("30ffc7"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Dysprosium",
+        symbol: "Dy",
+        description: "Dysprosium is a lanthanide with the chemical symbol Dy and atomic number 66.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Dysprosium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 66u8,
+            nucleon_number: 163u16,
+            atomic_mass: 162.5f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1685f64),
+            boiling_point: Some(2840f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 10u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f10 6s2",
+            ionisation_energies: &[573f64],
+            shells: &[2u8, 8u8, 18u8, 28u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.22f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(8.55f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "lanthanide",
+            period: 6u8,
+            xpos: 12u8,
+            ypos: 9u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("1fffc7"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Holmium",
+        symbol: "Ho",
+        description: "Holmium is a lanthanide with the chemical symbol Ho and atomic number 67.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Holmium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 67u8,
+            nucleon_number: 165u16,
+            atomic_mass: 164.93033f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1747f64),
+            boiling_point: Some(2973f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 11u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f11 6s2",
+            ionisation_energies: &[581f64],
+            shells: &[2u8, 8u8, 18u8, 29u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.23f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(8.8f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "lanthanide",
+            period: 6u8,
+            xpos: 13u8,
+            ypos: 9u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("00ff9c"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Erbium",
+        symbol: "Er",
+        description: "Erbium is a lanthanide with the chemical symbol Er and atomic number 68.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Erbium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 68u8,
+            nucleon_number: 167u16,
+            atomic_mass: 167.26f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1802f64),
+            boiling_point: Some(3141f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 12u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f12 6s2",
+            ionisation_energies: &[589.3f64],
+            shells: &[2u8, 8u8, 18u8, 30u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.24f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(9.07f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "lanthanide",
+            period: 6u8,
+            xpos: 14u8,
+            ypos: 9u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("00e675"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Thulium",
+        symbol: "Tm",
+        description: "Thulium is a lanthanide with the chemical symbol Tm and atomic number 69.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Thulium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 69u8,
+            nucleon_number: 169u16,
+            atomic_mass: 168.93422f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1818f64),
+            boiling_point: Some(2223f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 13u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f13 6s2",
+            ionisation_energies: &[596.7f64],
+            shells: &[2u8, 8u8, 18u8, 31u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.25f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(9.32f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "lanthanide",
+            period: 6u8,
+            xpos: 15u8,
+            ypos: 9u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("00d452"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Ytterbium",
+        symbol: "Yb",
+        description: "Ytterbium is a lanthanide with the chemical symbol Yb and atomic number 70.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Ytterbium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 70u8,
+            nucleon_number: 173u16,
+            atomic_mass: 173.04f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1092f64),
+            boiling_point: Some(1469f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 6s2",
+            ionisation_energies: &[603.4f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(6.9f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "lanthanide",
+            period: 6u8,
+            xpos: 16u8,
+            ypos: 9u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("00bf38"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Lutetium",
+        symbol: "Lu",
+        description: "Lutetium is a lanthanide with the chemical symbol Lu and atomic number 71.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Lutetium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 71u8,
+            nucleon_number: 175u16,
+            atomic_mass: 174.967f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1936f64),
+            boiling_point: Some(3675f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 1u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d1 6s2",
+            ionisation_energies: &[523.5f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 9u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.27f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(9.84f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "lanthanide",
+            period: 6u8,
+            xpos: 17u8,
+            ypos: 9u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("00ab24"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Hafnium",
+        symbol: "Hf",
+        description: "Hafnium is a transition metal with the chemical symbol Hf and atomic number 72.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Hafnium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 72u8,
+            nucleon_number: 179u16,
+            atomic_mass: 178.5f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(2506f64),
+            boiling_point: Some(4876f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 2u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d2 6s2",
+            ionisation_energies: &[658.5f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 10u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.3f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(13.3f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 6u8,
+            xpos: 4u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("4dc2ff"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Tantalum",
+        symbol: "Ta",
+        description: "Tantalum is a transition metal with the chemical symbol Ta and atomic number 73.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Tantalum",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 73u8,
+            nucleon_number: 181u16,
+            atomic_mass: 180.9479f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(3290f64),
+            boiling_point: Some(5731f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 3u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d3 6s2",
+            ionisation_energies: &[761.3f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 11u8, 2u8],
+            electron_affinity: Some(31.068f64),
+            electronegativity: Some(1.5f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(16.4f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 6u8,
+            xpos: 5u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("4da6ff"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Tungsten",
+        symbol: "W",
+        description: "Tungsten is a transition metal with the chemical symbol W and atomic number 74.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Tungsten",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 74u8,
+            nucleon_number: 184u16,
+            atomic_mass: 183.8f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(3695f64),
+            boiling_point: Some(5828f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 4u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d4 6s2",
+            ionisation_energies: &[770f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 12u8, 2u8],
+            electron_affinity: Some(78.636f64),
+            electronegativity: Some(2.36f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(19.3f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 6u8,
+            xpos: 6u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("2194d6"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Rhenium",
+        symbol: "Re",
+        description: "Rhenium is a transition metal with the chemical symbol Re and atomic number 75.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Rhenium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 75u8,
+            nucleon_number: 186u16,
+            atomic_mass: 186.21f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(3459f64),
+            boiling_point: Some(5869f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 5u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d5 6s2",
+            ionisation_energies: &[760.3f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 13u8, 2u8],
+            electron_affinity: Some(14.473f64),
+            electronegativity: Some(1.9f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(20.8f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 6u8,
+            xpos: 7u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("267dab"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Osmium",
+        symbol: "Os",
+        description: "Osmium is a transition metal with the chemical symbol Os and atomic number 76.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Osmium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 76u8,
+            nucleon_number: 190u16,
+            atomic_mass: 190.2f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(3306f64),
+            boiling_point: Some(5285f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 6u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d6 6s2",
+            ionisation_energies: &[839.4f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 14u8, 2u8],
+            electron_affinity: Some(106.134f64),
+            electronegativity: Some(2.2f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(22.57f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 6u8,
+            xpos: 8u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("266696"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Iridium",
+        symbol: "Ir",
+        description: "Iridium is a transition metal with the chemical symbol Ir and atomic number 77.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Iridium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 77u8,
+            nucleon_number: 192u16,
+            atomic_mass: 192.22f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(2719f64),
+            boiling_point: Some(4701f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 7u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d7 6s2",
+            ionisation_energies: &[878f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 15u8, 2u8],
+            electron_affinity: Some(151f64),
+            electronegativity: Some(2.2f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(22.42f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 6u8,
+            xpos: 9u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("175487"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Platinum",
+        symbol: "Pt",
+        description: "Platinum is a transition metal with the chemical symbol Pt and atomic number 78.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Platinum",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 78u8,
+            nucleon_number: 195u16,
+            atomic_mass: 195.08f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(2041.55f64),
+            boiling_point: Some(4098f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 9u8, 0u8, 0u8, 0u8],
+                    [1u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d9 6s1",
+            ionisation_energies: &[868.4f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 17u8, 1u8],
+            electron_affinity: Some(205.321f64),
+            electronegativity: Some(2.28f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(21.46f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 6u8,
+            xpos: 10u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("d0d0e0"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Gold",
+        symbol: "Au",
+        description: "Gold is a transition metal with the chemical symbol Au and atomic number 79.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Gold",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 79u8,
+            nucleon_number: 197u16,
+            atomic_mass: 196.96657f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1337.33f64),
+            boiling_point: Some(3129f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [1u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d10 6s1",
+            ionisation_energies: &[890.2f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 18u8, 1u8],
+            electron_affinity: Some(222.785f64),
+            electronegativity: Some(2.54f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(19.282f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 6u8,
+            xpos: 11u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("ffd123"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Mercury",
+        symbol: "Hg",
+        description: "Mercury is a transition metal with the chemical symbol Hg and atomic number 80.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Mercury",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 80u8,
+            nucleon_number: 201u16,
+            atomic_mass: 200.59f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(234.32f64),
+            boiling_point: Some(629.88f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d10 6s2",
+            ionisation_energies: &[1007.1f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 18u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(2f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(13.5336f64),
+            phase: "Liquid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 6u8,
+            xpos: 12u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("b8b8d0"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Thallium",
+        symbol: "Tl",
+        description: "Thallium is a post-transition metal with the chemical symbol Tl and atomic number 81.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Thallium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 81u8,
+            nucleon_number: 204u16,
+            atomic_mass: 204.383f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(577f64),
+            boiling_point: Some(1746f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 1u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d10 6s2 6p1",
+            ionisation_energies: &[589.3f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 18u8, 3u8],
+            electron_affinity: Some(19.297f64),
+            electronegativity: Some(1.62f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(11.8f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "post-transition metal",
+            period: 6u8,
+            xpos: 13u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("a6544d"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Lead",
+        symbol: "Pb",
+        description: "Lead is a post-transition metal with the chemical symbol Pb and atomic number 82.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Lead",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 82u8,
+            nucleon_number: 207u16,
+            atomic_mass: 207f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(600.61f64),
+            boiling_point: Some(2022f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 2u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d10 6s2 6p2",
+            ionisation_energies: &[715.6f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 18u8, 4u8],
+            electron_affinity: Some(34.735f64),
+            electronegativity: Some(2.33f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(11.342f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "post-transition metal",
+            period: 6u8,
+            xpos: 14u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("575961"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Bismuth",
+        symbol: "Bi",
+        description: "Bismuth is a post-transition metal with the chemical symbol Bi and atomic number 83.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Bismuth",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 83u8,
+            nucleon_number: 209u16,
+            atomic_mass: 208.9804f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(544.55f64),
+            boiling_point: Some(1837f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 3u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d10 6s2 6p3",
+            ionisation_energies: &[703.3f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 18u8, 5u8],
+            electron_affinity: Some(91.275f64),
+            electronegativity: Some(2.02f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(9.807f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "post-transition metal",
+            period: 6u8,
+            xpos: 15u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("9e4fb5"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Polonium",
+        symbol: "Po",
+        description: "Polonium is a metalloid with the chemical symbol Po and atomic number 84.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Polonium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 84u8,
+            nucleon_number: 209u16,
+            atomic_mass: 208.98243f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(527f64),
+            boiling_point: Some(1235f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 4u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d10 6s2 6p4",
+            ionisation_energies: &[812.1f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 18u8, 6u8],
+            electron_affinity: Some(183.322f64),
+            electronegativity: Some(2f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(9.32f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "metalloid",
+            period: 6u8,
+            xpos: 16u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("ab5c00"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Astatine",
+        symbol: "At",
+        description: "Astatine is a halogen with the chemical symbol At and atomic number 85.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Astatine",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 85u8,
+            nucleon_number: 210u16,
+            atomic_mass: 209.98715f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(575f64),
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 5u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d10 6s2 6p5",
+            ionisation_energies: &[916.6f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 18u8, 7u8],
+            electron_affinity: Some(270.159f64),
+            electronegativity: Some(2.2f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(7f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "halogen",
+            period: 6u8,
+            xpos: 17u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("754f45"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Radon",
+        symbol: "Rn",
+        description: "Radon is a noble gas with the chemical symbol Rn and atomic number 86.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Radon",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 86u8,
+            nucleon_number: 222u16,
+            atomic_mass: 222.01758f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(202f64),
+            boiling_point: Some(211.45f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Xe] 4f14 5d10 6s2 6p6",
+            ionisation_energies: &[1036.7f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 18u8, 8u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(0.00973f64),
+            phase: "Gas",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "noble gas",
+            period: 6u8,
+            xpos: 18u8,
+            ypos: 6u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("428296"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Francium",
+        symbol: "Fr",
+        description: "Francium is an alkali metal with the chemical symbol Fr and atomic number 87.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Francium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 87u8,
+            nucleon_number: 223u16,
+            atomic_mass: 223.01973f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(300f64),
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [1u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 7s1",
+            ionisation_energies: &[376.3f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 18u8, 8u8, 1u8],
+            electron_affinity: Some(45.348f64),
+            electronegativity: Some(0.7f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "alkali metal",
+            period: 7u8,
+            xpos: 1u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("420066"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Radium",
+        symbol: "Ra",
+        description: "Radium is an alkaline earth metal with the chemical symbol Ra and atomic number 88.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Radium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 88u8,
+            nucleon_number: 226u16,
+            atomic_mass: 226.02541f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(973f64),
+            boiling_point: Some(1413f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 7s2",
+            ionisation_energies: &[509.3f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 18u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(0.9f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(5f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "alkaline earth metal",
+            period: 7u8,
+            xpos: 2u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("007d00"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Actinium",
+        symbol: "Ac",
+        description: "Actinium is an actinide with the chemical symbol Ac and atomic number 89.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Actinium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 89u8,
+            nucleon_number: 227u16,
+            atomic_mass: 227.02775f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1324f64),
+            boiling_point: Some(3471f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 1u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 6d1 7s2",
+            ionisation_energies: &[498.8f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 18u8, 9u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.1f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(10.07f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "actinide",
+            period: 7u8,
+            xpos: 3u8,
+            ypos: 10u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("70abfa"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Thorium",
+        symbol: "Th",
+        description: "Thorium is an actinide with the chemical symbol Th and atomic number 90.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Thorium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 90u8,
+            nucleon_number: 232u16,
+            atomic_mass: 232.038f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(2023f64),
+            boiling_point: Some(5061f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 2u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 6d2 7s2",
+            ionisation_energies: &[586.6f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 18u8, 10u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.3f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(11.72f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "actinide",
+            period: 7u8,
+            xpos: 4u8,
+            ypos: 10u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("00baff"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Protactinium",
+        symbol: "Pa",
+        description: "Protactinium is an actinide with the chemical symbol Pa and atomic number 91.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Protactinium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 91u8,
+            nucleon_number: 231u16,
+            atomic_mass: 231.0359f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1845f64),
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 2u8, 0u8, 0u8],
+                    [2u8, 6u8, 1u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f2 6d1 7s2",
+            ionisation_energies: &[568.3f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 20u8, 9u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.5f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(15.37f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "actinide",
+            period: 7u8,
+            xpos: 5u8,
+            ypos: 10u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("00a1ff"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Uranium",
+        symbol: "U",
+        description: "Uranium is an actinide with the chemical symbol U and atomic number 92.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Uranium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 92u8,
+            nucleon_number: 238u16,
+            atomic_mass: 238.0289f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1408f64),
+            boiling_point: Some(4404f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 3u8, 0u8, 0u8],
+                    [2u8, 6u8, 1u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f3 6d1 7s2",
+            ionisation_energies: &[597.6f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 21u8, 9u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.38f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(18.95f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "actinide",
+            period: 7u8,
+            xpos: 6u8,
+            ypos: 10u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("008fff"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Neptunium",
+        symbol: "Np",
+        description: "Neptunium is an actinide with the chemical symbol Np and atomic number 93.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Neptunium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 93u8,
+            nucleon_number: 237u16,
+            atomic_mass: 237.04817f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(917f64),
+            boiling_point: Some(4175f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 4u8, 0u8, 0u8],
+                    [2u8, 6u8, 1u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f4 6d1 7s2",
+            ionisation_energies: &[604.6f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 22u8, 9u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.36f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(20.25f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "actinide",
+            period: 7u8,
+            xpos: 7u8,
+            ypos: 10u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("0080ff"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Plutonium",
+        symbol: "Pu",
+        description: "Plutonium is an actinide with the chemical symbol Pu and atomic number 94.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Plutonium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 94u8,
+            nucleon_number: 244u16,
+            atomic_mass: 244.0642f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(913f64),
+            boiling_point: Some(3501f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 6u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f6 7s2",
+            ionisation_energies: &[584.7f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 24u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.28f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(19.84f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "actinide",
+            period: 7u8,
+            xpos: 8u8,
+            ypos: 10u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("006bff"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Americium",
+        symbol: "Am",
+        description: "Americium is an actinide with the chemical symbol Am and atomic number 95.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Americium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 95u8,
+            nucleon_number: 243u16,
+            atomic_mass: 243.06138f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1449f64),
+            boiling_point: Some(2284f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 7u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f7 7s2",
+            ionisation_energies: &[578.2f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 25u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.3f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(13.69f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "actinide",
+            period: 7u8,
+            xpos: 9u8,
+            ypos: 10u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("545cf2"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Curium",
+        symbol: "Cm",
+        description: "Curium is an actinide with the chemical symbol Cm and atomic number 96.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Curium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 96u8,
+            nucleon_number: 247u16,
+            atomic_mass: 247.07035f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1618f64),
+            boiling_point: Some(3400f64),
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 7u8, 0u8, 0u8],
+                    [2u8, 6u8, 1u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f7 6d1 7s2",
+            ionisation_energies: &[580.8f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 25u8, 9u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.3f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(13.51f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "actinide",
+            period: 7u8,
+            xpos: 10u8,
+            ypos: 10u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("785ce3"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Berkelium",
+        symbol: "Bk",
+        description: "Berkelium is an actinide with the chemical symbol Bk and atomic number 97.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Berkelium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 97u8,
+            nucleon_number: 247u16,
+            atomic_mass: 247.07031f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1323f64),
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 9u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f9 7s2",
+            ionisation_energies: &[601.1f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 27u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.3f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: Some(14f64),
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "actinide",
+            period: 7u8,
+            xpos: 11u8,
+            ypos: 10u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("8a4fe3"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Californium",
+        symbol: "Cf",
+        description: "Californium is an actinide with the chemical symbol Cf and atomic number 98.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Californium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 98u8,
+            nucleon_number: 251u16,
+            atomic_mass: 251.07959f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1173f64),
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 10u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f10 7s2",
+            ionisation_energies: &[607.9f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 28u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.3f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "actinide",
+            period: 7u8,
+            xpos: 12u8,
+            ypos: 10u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("a136d4"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Einsteinium",
+        symbol: "Es",
+        description: "Einsteinium is an actinide with the chemical symbol Es and atomic number 99.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Einsteinium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 99u8,
+            nucleon_number: 252u16,
+            atomic_mass: 252.083f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1133f64),
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 11u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f11 7s2",
+            ionisation_energies: &[619.4f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 29u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.3f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "actinide",
+            period: 7u8,
+            xpos: 13u8,
+            ypos: 10u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("b31fd4"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Fermium",
+        symbol: "Fm",
+        description: "Fermium is an actinide with the chemical symbol Fm and atomic number 100.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Fermium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 100u8,
+            nucleon_number: 257u16,
+            atomic_mass: 257.09511f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1800f64),
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 12u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f12 7s2",
+            ionisation_energies: &[627.2f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 30u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.3f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "actinide",
+            period: 7u8,
+            xpos: 14u8,
+            ypos: 10u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("b31fba"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Mendelevium",
+        symbol: "Md",
+        description: "Mendelevium is an actinide with the chemical symbol Md and atomic number 101.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Mendelevium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 101u8,
+            nucleon_number: 258u16,
+            atomic_mass: 258.09843f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1100f64),
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 13u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f13 7s2",
+            ionisation_energies: &[634.9f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 31u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.3f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "actinide",
+            period: 7u8,
+            xpos: 15u8,
+            ypos: 10u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("b30da6"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Nobelium",
+        symbol: "No",
+        description: "Nobelium is an actinide with the chemical symbol No and atomic number 102.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Nobelium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 102u8,
+            nucleon_number: 259u16,
+            atomic_mass: 259.101f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1100f64),
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 7s2",
+            ionisation_energies: &[641.6f64],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 8u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.3f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "actinide",
+            period: 7u8,
+            xpos: 16u8,
+            ypos: 10u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("bd0d87"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Lawrencium",
+        symbol: "Lr",
+        description: "Lawrencium is an actinide with the chemical symbol Lr and atomic number 103.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Lawrencium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 103u8,
+            nucleon_number: 262u16,
+            atomic_mass: 262.11f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: Some(1900f64),
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 1u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d1 7s2",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 9u8, 2u8],
+            electron_affinity: None,
+            electronegativity: Some(1.3f64),
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "actinide",
+            period: 7u8,
+            xpos: 17u8,
+            ypos: 10u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("c70066"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Rutherfordium",
+        symbol: "Rf",
+        description: "Rutherfordium is a transition metal with the chemical symbol Rf and atomic number 104.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Rutherfordium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 104u8,
+            nucleon_number: 267u16,
+            atomic_mass: 267.122f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: None,
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 2u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d2 7s2",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 10u8, 2u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 7u8,
+            xpos: 4u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("cc0059"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Dubnium",
+        symbol: "Db",
+        description: "Dubnium is a transition metal with the chemical symbol Db and atomic number 105.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Dubnium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 105u8,
+            nucleon_number: 268u16,
+            atomic_mass: 268.126f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: None,
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 3u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d3 7s2",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 11u8, 2u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 7u8,
+            xpos: 5u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("d1004f"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Seaborgium",
+        symbol: "Sg",
+        description: "Seaborgium is a transition metal with the chemical symbol Sg and atomic number 106.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Seaborgium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 106u8,
+            nucleon_number: 271u16,
+            atomic_mass: 271.134f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: None,
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 4u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d4 7s2",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 12u8, 2u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 7u8,
+            xpos: 6u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("d90045"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Bohrium",
+        symbol: "Bh",
+        description: "Bohrium is a transition metal with the chemical symbol Bh and atomic number 107.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Bohrium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 107u8,
+            nucleon_number: 274u16,
+            atomic_mass: 274.144f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: None,
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 5u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d5 7s2",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 13u8, 2u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 7u8,
+            xpos: 7u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("e00038"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Hassium",
+        symbol: "Hs",
+        description: "Hassium is a transition metal with the chemical symbol Hs and atomic number 108.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Hassium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 108u8,
+            nucleon_number: 277u16,
+            atomic_mass: 277.152f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: None,
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 6u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d6 7s2",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 14u8, 2u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 7u8,
+            xpos: 8u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("e6002e"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Meitnerium",
+        symbol: "Mt",
+        description: "Meitnerium is a transition metal with the chemical symbol Mt and atomic number 109.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Meitnerium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 109u8,
+            nucleon_number: 278u16,
+            atomic_mass: 278.156f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: None,
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 7u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d7 7s2",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 15u8, 2u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 7u8,
+            xpos: 9u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: Some("eb0026"),
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Darmstadtium",
+        symbol: "Ds",
+        description: "Darmstadtium is a transition metal with the chemical symbol Ds and atomic number 110.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Darmstadtium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 110u8,
+            nucleon_number: 281u16,
+            atomic_mass: 281.165f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: None,
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 8u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d8 7s2",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 16u8, 2u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 7u8,
+            xpos: 10u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: None,
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Roentgenium",
+        symbol: "Rg",
+        description: "Roentgenium is a transition metal with the chemical symbol Rg and atomic number 111.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Roentgenium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 111u8,
+            nucleon_number: 282u16,
+            atomic_mass: 282.169f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: None,
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 9u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d9 7s2",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 17u8, 2u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 7u8,
+            xpos: 11u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: None,
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Copernicium",
+        symbol: "Cn",
+        description: "Copernicium is a transition metal with the chemical symbol Cn and atomic number 112.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Copernicium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 112u8,
+            nucleon_number: 285u16,
+            atomic_mass: 285.177f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: None,
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d10 7s2",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 18u8, 2u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "transition metal",
+            period: 7u8,
+            xpos: 12u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: None,
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Nihonium",
+        symbol: "Nh",
+        description: "Nihonium is a post-transition metal with the chemical symbol Nh and atomic number 113.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Nihonium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 113u8,
+            nucleon_number: 286u16,
+            atomic_mass: 286.183f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: None,
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 1u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d10 7s2 7p1",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 18u8, 3u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "post-transition metal",
+            period: 7u8,
+            xpos: 13u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: None,
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Flerovium",
+        symbol: "Fl",
+        description: "Flerovium is a post-transition metal with the chemical symbol Fl and atomic number 114.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Flerovium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 114u8,
+            nucleon_number: 289u16,
+            atomic_mass: 289.191f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: None,
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 2u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d10 7s2 7p2",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 18u8, 4u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "post-transition metal",
+            period: 7u8,
+            xpos: 14u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: None,
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Moscovium",
+        symbol: "Mc",
+        description: "Moscovium is a post-transition metal with the chemical symbol Mc and atomic number 115.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Moscovium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 115u8,
+            nucleon_number: 290u16,
+            atomic_mass: 290.196f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: None,
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 3u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d10 7s2 7p3",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 18u8, 5u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "post-transition metal",
+            period: 7u8,
+            xpos: 15u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: None,
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Livermorium",
+        symbol: "Lv",
+        description: "Livermorium is a post-transition metal with the chemical symbol Lv and atomic number 116.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Livermorium",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 116u8,
+            nucleon_number: 293u16,
+            atomic_mass: 293.205f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: None,
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 4u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d10 7s2 7p4",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 18u8, 6u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "post-transition metal",
+            period: 7u8,
+            xpos: 16u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: None,
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Tennessine",
+        symbol: "Ts",
+        description: "Tennessine is a halogen with the chemical symbol Ts and atomic number 117.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Tennessine",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 117u8,
+            nucleon_number: 294u16,
+            atomic_mass: 294.211f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: None,
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 5u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d10 7s2 7p5",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 18u8, 7u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Solid",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "halogen",
+            period: 7u8,
+            xpos: 17u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: None,
+            spectral_img: None,
+        },
+    },
+    &chemistru_elements::inner::InnerElement {
+        name: "Oganesson",
+        symbol: "Og",
+        description: "Oganesson is a noble gas with the chemical symbol Og and atomic number 118.",
+        named_by: None,
+        source: "https://en.wikipedia.org/wiki/Oganesson",
+        atomic_data: chemistru_elements::data::atomic::AtomicData {
+            atomic_number: 118u8,
+            nucleon_number: 294u16,
+            atomic_mass: 294.214f64,
+        },
+        state_data: chemistru_elements::data::state::StateData {
+            melting_point: None,
+            boiling_point: None,
+        },
+        electron_data: chemistru_elements::data::electron::ElectronData {
+            electron_configuration:
+                chemistru_elements::data::electron::configuration::ElectronConfiguration::from_counts([
+                    [2u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 14u8, 0u8, 0u8],
+                    [2u8, 6u8, 10u8, 0u8, 0u8, 0u8],
+                    [2u8, 6u8, 0u8, 0u8, 0u8, 0u8],
+                ]),
+            electron_configuration_semantic: "[Rn] 5f14 6d10 7s2 7p6",
+            ionisation_energies: &[],
+            shells: &[2u8, 8u8, 18u8, 32u8, 32u8, 18u8, 8u8],
+            electron_affinity: None,
+            electronegativity: None,
+        },
+        physical_data: chemistru_elements::data::physical::PhysicalData {
+            density: None,
+            phase: "Gas",
+            molar_heat: None,
+        },
+        table_data: chemistru_elements::data::table::TableData {
+            category: "noble gas",
+            period: 7u8,
+            xpos: 18u8,
+            ypos: 7u8,
+        },
+        display_data: chemistru_elements::data::display::DisplayData {
+            appearance: None,
+            color: None,
+            cpk_hex: None,
+            spectral_img: None,
+        },
+    },
+];
+#[doc = r" Indices into `ELEMENTS` keyed by lowercase symbol"]
+pub static BY_SYMBOL: phf::Map<&'static str, usize> = phf::phf_map! { "h" => 0 , "he" => 1 , "li" => 2 , "be" => 3 , "b" => 4 , "c" => 5 , "n" => 6 , "o" => 7 , "f" => 8 , "ne" => 9 , "na" => 10 , "mg" => 11 , "al" => 12 , "si" => 13 , "p" => 14 , "s" => 15 , "cl" => 16 , "ar" => 17 , "k" => 18 , "ca" => 19 , "sc" => 20 , "ti" => 21 , "v" => 22 , "cr" => 23 , "mn" => 24 , "fe" => 25 , "co" => 26 , "ni" => 27 , "cu" => 28 , "zn" => 29 , "ga" => 30 , "ge" => 31 , "as" => 32 , "se" => 33 , "br" => 34 , "kr" => 35 , "rb" => 36 , "sr" => 37 , "y" => 38 , "zr" => 39 , "nb" => 40 , "mo" => 41 , "tc" => 42 , "ru" => 43 , "rh" => 44 , "pd" => 45 , "ag" => 46 , "cd" => 47 , "in" => 48 , "sn" => 49 , "sb" => 50 , "te" => 51 , "i" => 52 , "xe" => 53 , "cs" => 54 , "ba" => 55 , "la" => 56 , "ce" => 57 , "pr" => 58 , "nd" => 59 , "pm" => 60 , "sm" => 61 , "eu" => 62 , "gd" => 63 , "tb" => 64 , "dy" => 65 , "ho" => 66 , "er" => 67 , "tm" => 68 , "yb" => 69 , "lu" => 70 , "hf" => 71 , "ta" => 72 , "w" => 73 , "re" => 74 , "os" => 75 , "ir" => 76 , "pt" => 77 , "au" => 78 , "hg" => 79 , "tl" => 80 , "pb" => 81 , "bi" => 82 , "po" => 83 , "at" => 84 , "rn" => 85 , "fr" => 86 , "ra" => 87 , "ac" => 88 , "th" => 89 , "pa" => 90 , "u" => 91 , "np" => 92 , "pu" => 93 , "am" => 94 , "cm" => 95 , "bk" => 96 , "cf" => 97 , "es" => 98 , "fm" => 99 , "md" => 100 , "no" => 101 , "lr" => 102 , "rf" => 103 , "db" => 104 , "sg" => 105 , "bh" => 106 , "hs" => 107 , "mt" => 108 , "ds" => 109 , "rg" => 110 , "cn" => 111 , "nh" => 112 , "fl" => 113 , "mc" => 114 , "lv" => 115 , "ts" => 116 , "og" => 117 };
+#[doc = r" Indices into `ELEMENTS` keyed by lowercase name"]
+pub static BY_NAME: phf::Map<&'static str, usize> = phf::phf_map! { "hydrogen" => 0 , "helium" => 1 , "lithium" => 2 , "beryllium" => 3 , "boron" => 4 , "carbon" => 5 , "nitrogen" => 6 , "oxygen" => 7 , "fluorine" => 8 , "neon" => 9 , "sodium" => 10 , "magnesium" => 11 , "aluminium" => 12 , "silicon" => 13 , "phosphorus" => 14 , "sulfur" => 15 , "chlorine" => 16 , "argon" => 17 , "potassium" => 18 , "calcium" => 19 , "scandium" => 20 , "titanium" => 21 , "vanadium" => 22 , "chromium" => 23 , "manganese" => 24 , "iron" => 25 , "cobalt" => 26 , "nickel" => 27 , "copper" => 28 , "zinc" => 29 , "gallium" => 30 , "germanium" => 31 , "arsenic" => 32 , "selenium" => 33 , "bromine" => 34 , "krypton" => 35 , "rubidium" => 36 , "strontium" => 37 , "yttrium" => 38 , "zirconium" => 39 , "niobium" => 40 , "molybdenum" => 41 , "technetium" => 42 , "ruthenium" => 43 , "rhodium" => 44 , "palladium" => 45 , "silver" => 46 , "cadmium" => 47 , "indium" => 48 , "tin" => 49 , "antimony" => 50 , "tellurium" => 51 , "iodine" => 52 , "xenon" => 53 , "caesium" => 54 , "barium" => 55 , "lanthanum" => 56 , "cerium" => 57 , "praseodymium" => 58 , "neodymium" => 59 , "promethium" => 60 , "samarium" => 61 , "europium" => 62 , "gadolinium" => 63 , "terbium" => 64 , "dysprosium" => 65 , "holmium" => 66 , "erbium" => 67 , "thulium" => 68 , "ytterbium" => 69 , "lutetium" => 70 , "hafnium" => 71 , "tantalum" => 72 , "tungsten" => 73 , "rhenium" => 74 , "osmium" => 75 , "iridium" => 76 , "platinum" => 77 , "gold" => 78 , "mercury" => 79 , "thallium" => 80 , "lead" => 81 , "bismuth" => 82 , "polonium" => 83 , "astatine" => 84 , "radon" => 85 , "francium" => 86 , "radium" => 87 , "actinium" => 88 , "thorium" => 89 , "protactinium" => 90 , "uranium" => 91 , "neptunium" => 92 , "plutonium" => 93 , "americium" => 94 , "curium" => 95 , "berkelium" => 96 , "californium" => 97 , "einsteinium" => 98 , "fermium" => 99 , "mendelevium" => 100 , "nobelium" => 101 , "lawrencium" => 102 , "rutherfordium" => 103 , "dubnium" => 104 , "seaborgium" => 105 , "bohrium" => 106 , "hassium" => 107 , "meitnerium" => 108 , "darmstadtium" => 109 , "roentgenium" => 110 , "copernicium" => 111 , "nihonium" => 112 , "flerovium" => 113 , "moscovium" => 114 , "livermorium" => 115 , "tennessine" => 116 , "oganesson" => 117 };
+pub const HYDROGEN: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::H);
+pub const HELIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::He);
+pub const LITHIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Li);
+pub const BERYLLIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Be);
+pub const BORON: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::B);
+pub const CARBON: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::C);
+pub const NITROGEN: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::N);
+pub const OXYGEN: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::O);
+pub const FLUORINE: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::F);
+pub const NEON: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ne);
+pub const SODIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Na);
+pub const MAGNESIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Mg);
+pub const ALUMINIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Al);
+pub const SILICON: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Si);
+pub const PHOSPHORUS: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::P);
+pub const SULFUR: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::S);
+pub const CHLORINE: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Cl);
+pub const ARGON: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ar);
+pub const POTASSIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::K);
+pub const CALCIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ca);
+pub const SCANDIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Sc);
+pub const TITANIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ti);
+pub const VANADIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::V);
+pub const CHROMIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Cr);
+pub const MANGANESE: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Mn);
+pub const IRON: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Fe);
+pub const COBALT: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Co);
+pub const NICKEL: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ni);
+pub const COPPER: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Cu);
+pub const ZINC: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Zn);
+pub const GALLIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ga);
+pub const GERMANIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ge);
+pub const ARSENIC: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::As);
+pub const SELENIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Se);
+pub const BROMINE: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Br);
+pub const KRYPTON: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Kr);
+pub const RUBIDIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Rb);
+pub const STRONTIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Sr);
+pub const YTTRIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Y);
+pub const ZIRCONIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Zr);
+pub const NIOBIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Nb);
+pub const MOLYBDENUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Mo);
+pub const TECHNETIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Tc);
+pub const RUTHENIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ru);
+pub const RHODIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Rh);
+pub const PALLADIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Pd);
+pub const SILVER: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ag);
+pub const CADMIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Cd);
+pub const INDIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::In);
+pub const TIN: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Sn);
+pub const ANTIMONY: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Sb);
+pub const TELLURIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Te);
+pub const IODINE: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::I);
+pub const XENON: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Xe);
+pub const CAESIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Cs);
+pub const BARIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ba);
+pub const LANTHANUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::La);
+pub const CERIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ce);
+pub const PRASEODYMIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Pr);
+pub const NEODYMIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Nd);
+pub const PROMETHIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Pm);
+pub const SAMARIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Sm);
+pub const EUROPIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Eu);
+pub const GADOLINIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Gd);
+pub const TERBIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Tb);
+pub const DYSPROSIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Dy);
+pub const HOLMIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ho);
+pub const ERBIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Er);
+pub const THULIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Tm);
+pub const YTTERBIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Yb);
+pub const LUTETIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Lu);
+pub const HAFNIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Hf);
+pub const TANTALUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ta);
+pub const TUNGSTEN: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::W);
+pub const RHENIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Re);
+pub const OSMIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Os);
+pub const IRIDIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ir);
+pub const PLATINUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Pt);
+pub const GOLD: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Au);
+pub const MERCURY: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Hg);
+pub const THALLIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Tl);
+pub const LEAD: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Pb);
+pub const BISMUTH: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Bi);
+pub const POLONIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Po);
+pub const ASTATINE: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::At);
+pub const RADON: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Rn);
+pub const FRANCIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Fr);
+pub const RADIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ra);
+pub const ACTINIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ac);
+pub const THORIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Th);
+pub const PROTACTINIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Pa);
+pub const URANIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::U);
+pub const NEPTUNIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Np);
+pub const PLUTONIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Pu);
+pub const AMERICIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Am);
+pub const CURIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Cm);
+pub const BERKELIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Bk);
+pub const CALIFORNIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Cf);
+pub const EINSTEINIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Es);
+pub const FERMIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Fm);
+pub const MENDELEVIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Md);
+pub const NOBELIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::No);
+pub const LAWRENCIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Lr);
+pub const RUTHERFORDIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Rf);
+pub const DUBNIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Db);
+pub const SEABORGIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Sg);
+pub const BOHRIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Bh);
+pub const HASSIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Hs);
+pub const MEITNERIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Mt);
+pub const DARMSTADTIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ds);
+pub const ROENTGENIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Rg);
+pub const COPERNICIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Cn);
+pub const NIHONIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Nh);
+pub const FLEROVIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Fl);
+pub const MOSCOVIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Mc);
+pub const LIVERMORIUM: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Lv);
+pub const TENNESSINE: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Ts);
+pub const OGANESSON: chemistru_elements::element::Element =
+    chemistru_elements::element::Element::from_symbol_enum(chemistru_elements::symbol::ElementSymbol::Og);
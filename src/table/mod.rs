@@ -0,0 +1,36 @@
+//! Built-in periodic table
+//!
+//! All 118 elements are compiled into the binary as static data, so no JSON
+//! has to be shipped or parsed at runtime.
+//!
+//! ```
+//! use chemistru_elements::element::Element;
+//! use chemistru_elements::table::{ELEMENTS, IRON};
+//!
+//! assert_eq!(ELEMENTS[25].symbol, "Fe");
+//! assert_eq!(IRON.atomic_number(), 26);
+//!
+//! let iron = Element::from_symbol("fe").unwrap();
+//! assert_eq!(iron.name(), "Iron");
+//! ```
+
+pub mod query;
+
+#[cfg(not(chemistru_bootstrap))]
+#[rustfmt::skip]
+mod generated;
+
+/// Empty stand-in for the generated table
+///
+/// Lets the crate compile while the generated table is out of date with the
+/// data structures, so that it can be regenerated.
+#[cfg(chemistru_bootstrap)]
+mod generated {
+    use crate::inner::InnerElement;
+
+    pub static ELEMENTS: [&InnerElement; 0] = [];
+    pub static BY_SYMBOL: phf::Map<&'static str, usize> = phf::Map::new();
+    pub static BY_NAME: phf::Map<&'static str, usize> = phf::Map::new();
+}
+
+pub use generated::*;
@@ -0,0 +1,126 @@
+//! Searches over the built-in table by electron configuration
+
+use std::ops::RangeInclusive;
+
+use crate::data::prelude::{DisplayOptions, ElectronConfiguration, SubshellKind, SubshellOrder};
+use crate::element::Element;
+use crate::ion::Ion;
+
+use super::ELEMENTS;
+
+/// Every element in the built-in table, in order of atomic number
+pub fn elements() -> impl Iterator<Item = Element> {
+    (1..=ELEMENTS.len() as u8).filter_map(Element::from_atomic_number)
+}
+
+/// Atoms and ions with the given configuration, trying every element with
+/// every charge in `charges`
+///
+/// Atoms are returned as ions with an oxidation state of zero, so `charges`
+/// must contain zero for them to be included.
+///
+/// ```
+/// use chemistru_elements::data::prelude::*;
+/// use chemistru_elements::table::query::isoelectronic_with;
+///
+/// let neon = ElectronConfiguration::ground_state(10).unwrap();
+/// let series = isoelectronic_with(&neon, -3..=3)
+///     .iter()
+///     .map(|ion| (ion.element.symbol(), ion.oxidation_state))
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(series[0], ("N".to_string(), -3));
+/// assert_eq!(series[3], ("Ne".to_string(), 0));
+/// assert_eq!(series[6], ("Al".to_string(), 3));
+/// ```
+pub fn isoelectronic_with(
+    configuration: &ElectronConfiguration,
+    charges: RangeInclusive<i8>,
+) -> Vec<Ion> {
+    elements()
+        .flat_map(|element| {
+            charges.clone().map(move |oxidation_state| Ion {
+                element,
+                oxidation_state,
+            })
+        })
+        .filter(|ion| {
+            ion.electron_configuration()
+                .is_ok_and(|ion_configuration| ion_configuration == *configuration)
+        })
+        .collect()
+}
+
+/// Elements whose ground state, written in full in Madelung order, matches
+/// `pattern`
+///
+/// `*` matches any run of characters and `?` matches any single character, so
+/// `*d5` finds every element whose last filled subshell is a d5.
+///
+/// ```
+/// use chemistru_elements::table::query::elements_matching;
+///
+/// let symbols = elements_matching("*d5")
+///     .iter()
+///     .map(|e| e.symbol())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(symbols, ["Cr", "Mn", "Mo", "Tc", "Re", "Bh"]);
+/// ```
+pub fn elements_matching(pattern: &str) -> Vec<Element> {
+    let options = DisplayOptions {
+        order: SubshellOrder::Energy,
+        ..Default::default()
+    };
+
+    let pattern = pattern.chars().collect::<Vec<_>>();
+
+    elements()
+        .filter(|element| {
+            let configuration = element
+                .electron_configuration()
+                .display(options)
+                .to_string();
+
+            glob(&pattern, &configuration.chars().collect::<Vec<_>>())
+        })
+        .collect()
+}
+
+/// Elements whose last subshell in Madelung order is of the given kind and
+/// holds the given number of electrons
+///
+/// ```
+/// use chemistru_elements::data::prelude::*;
+/// use chemistru_elements::table::query::ending_subshell;
+///
+/// let symbols = ending_subshell(SubshellKind::P, 5)
+///     .iter()
+///     .map(|e| e.symbol())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(symbols, ["F", "Cl", "Br", "I", "At", "Ts"]);
+/// ```
+pub fn ending_subshell(kind: SubshellKind, electrons: u8) -> Vec<Element> {
+    elements()
+        .filter(|element| {
+            element
+                .electron_configuration()
+                .iter_by_energy()
+                .last()
+                .is_some_and(|last| last.kind() == kind && last.electrons() == electrons)
+        })
+        .collect()
+}
+
+/// Match `text` against a pattern of literal characters, `*` and `?`
+fn glob(pattern: &[char], text: &[char]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => (0..=text.len()).any(|skip| glob(rest, &text[skip..])),
+        Some((&c, rest)) => match text.split_first() {
+            Some((&t, text)) => (c == '?' || c == t) && glob(rest, text),
+            None => false,
+        },
+    }
+}
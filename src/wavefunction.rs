@@ -0,0 +1,383 @@
+//! Hydrogen-like atomic orbital wavefunctions
+//!
+//! Distances are in units of a₀/Z, the Bohr radius divided by the nuclear
+//! charge, so the same functions describe any single electron species.
+//! Angular parts are the real spherical harmonics used to draw orbitals, with
+//! m = +1 and m = -1 being p_x and p_y.
+//!
+//! ```
+//! use chemistru_elements::wavefunction::Orbital;
+//!
+//! let orbital = Orbital::new(2, 1, 0).unwrap();
+//!
+//! assert_eq!(orbital.to_string(), "2p_z");
+//! assert_eq!(orbital.radial_nodes(), 0);
+//! assert!((orbital.most_probable_radius() - 4.0).abs() < 1e-6);
+//! ```
+
+use std::f64::consts::PI;
+use std::fmt;
+
+use crate::data::electron::orbital::BLOCKS;
+use crate::data::electron::quantum::QuantumNumbers;
+use crate::data::electron::subshell::Subshell;
+use crate::error::{Error, Result};
+
+/// Points in the radial tables used for the most probable radius and sampling
+const RADIAL_STEPS: usize = 8192;
+
+/// Single orbital with principal, angular momentum and magnetic quantum
+/// numbers
+///
+/// Only built through [`Orbital::new`], so the quantum numbers always satisfy
+/// n > l >= |m|.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Orbital {
+    n: u8,
+    l: u8,
+    m: i8,
+}
+
+impl Orbital {
+    pub fn new(n: u8, l: u8, m: i8) -> Result<Self> {
+        if n == 0 {
+            return Err(Error::InvalidQuantumNumber(n));
+        }
+
+        if l >= n {
+            return Err(Error::InvalidAngularMomentum(l));
+        }
+
+        if m.unsigned_abs() > l {
+            return Err(Error::InvalidMagneticQuantumNumber(m));
+        }
+
+        Ok(Self { n, l, m })
+    }
+
+    /// Principal quantum number
+    pub const fn n(&self) -> u8 {
+        self.n
+    }
+
+    /// Angular momentum quantum number
+    pub const fn l(&self) -> u8 {
+        self.l
+    }
+
+    /// Magnetic quantum number
+    pub const fn m(&self) -> i8 {
+        self.m
+    }
+
+    /// Radial wavefunction R_nl(r), normalised so that ∫ R² r² dr = 1
+    pub fn radial(&self, r: f64) -> f64 {
+        let n = self.n as f64;
+        let l = self.l as i32;
+        let k = self.n as u32 - self.l as u32 - 1;
+        let rho = 2.0 * r / n;
+
+        let norm =
+            ((2.0 / n).powi(3) / (2.0 * n * factorial_ratio(k, k + 2 * l as u32 + 1))).sqrt();
+
+        norm * (-rho / 2.0).exp() * rho.powi(l) * laguerre(k, 2.0 * l as f64 + 1.0, rho)
+    }
+
+    /// Real spherical harmonic at polar angle `theta` from the z axis and
+    /// azimuth `phi` from the x axis
+    pub fn angular(&self, theta: f64, phi: f64) -> f64 {
+        let l = self.l as u32;
+        let m = self.m.unsigned_abs() as u32;
+
+        let norm = ((2 * l + 1) as f64 / (4.0 * PI) / factorial_ratio(l - m, l + m)).sqrt();
+        let legendre = associated_legendre(l, m, theta.cos());
+
+        match self.m {
+            0 => norm * legendre,
+            m if m > 0 => 2f64.sqrt() * norm * legendre * (m as f64 * phi).cos(),
+            m => 2f64.sqrt() * norm * legendre * (m.unsigned_abs() as f64 * phi).sin(),
+        }
+    }
+
+    /// Wavefunction at spherical coordinates
+    pub fn psi(&self, r: f64, theta: f64, phi: f64) -> f64 {
+        self.radial(r) * self.angular(theta, phi)
+    }
+
+    /// Wavefunction at cartesian coordinates
+    pub fn psi_at(&self, [x, y, z]: [f64; 3]) -> f64 {
+        let r = (x * x + y * y + z * z).sqrt();
+
+        match r {
+            0.0 => self.psi(0.0, 0.0, 0.0),
+            _ => self.psi(r, (z / r).clamp(-1.0, 1.0).acos(), y.atan2(x)),
+        }
+    }
+
+    /// Probability density |ψ|² at cartesian coordinates
+    pub fn probability_density(&self, point: [f64; 3]) -> f64 {
+        self.psi_at(point).powi(2)
+    }
+
+    /// Probability density of finding the electron at distance `r` from the
+    /// nucleus, r² R_nl(r)²
+    pub fn radial_distribution(&self, r: f64) -> f64 {
+        (r * self.radial(r)).powi(2)
+    }
+
+    /// Number of spherical nodes, n - l - 1
+    pub const fn radial_nodes(&self) -> u8 {
+        self.n - self.l - 1
+    }
+
+    /// Number of planar or conical nodes, l
+    pub const fn angular_nodes(&self) -> u8 {
+        self.l
+    }
+
+    /// Total number of nodes, n - 1
+    pub const fn nodes(&self) -> u8 {
+        self.n - 1
+    }
+
+    /// Radius at which the radial distribution is greatest
+    pub fn most_probable_radius(&self) -> f64 {
+        let step = self.radial_extent() / RADIAL_STEPS as f64;
+
+        let peak = (1..RADIAL_STEPS)
+            .map(|idx| idx as f64 * step)
+            .max_by(|&a, &b| {
+                self.radial_distribution(a)
+                    .total_cmp(&self.radial_distribution(b))
+            })
+            .expect("Radial table is not empty");
+
+        // Golden section search within the neighbouring grid points
+        let ratio = (5f64.sqrt() - 1.0) / 2.0;
+        let (mut low, mut high) = (peak - step, peak + step);
+
+        while high - low > 1e-12 * high {
+            let a = high - ratio * (high - low);
+            let b = low + ratio * (high - low);
+
+            match self.radial_distribution(a) < self.radial_distribution(b) {
+                true => low = a,
+                false => high = b,
+            }
+        }
+
+        (low + high) / 2.0
+    }
+
+    /// Points distributed according to the probability density, for drawing
+    /// the orbital as a cloud
+    ///
+    /// The same seed always gives the same points. Radii are drawn from a
+    /// table of the radial distribution, and directions by rejection against
+    /// the bound (2l + 1) / 4π on the squared spherical harmonic.
+    ///
+    /// ```
+    /// use chemistru_elements::wavefunction::Orbital;
+    ///
+    /// let orbital = Orbital::new(1, 0, 0).unwrap();
+    /// let points = orbital.sample(10_000, 42);
+    ///
+    /// let mean = points.iter().map(|[x, y, z]| (x * x + y * y + z * z).sqrt()).sum::<f64>()
+    ///     / points.len() as f64;
+    ///
+    /// // The mean radius of 1s is 1.5 a₀
+    /// assert!((mean - 1.5).abs() < 0.05);
+    /// assert_eq!(points, orbital.sample(10_000, 42));
+    /// ```
+    pub fn sample(&self, count: usize, seed: u64) -> Vec<[f64; 3]> {
+        let mut rng = SplitMix64(seed);
+
+        let step = self.radial_extent() / RADIAL_STEPS as f64;
+        let radii = (0..=RADIAL_STEPS)
+            .map(|idx| idx as f64 * step)
+            .collect::<Vec<_>>();
+
+        // Cumulative radial distribution by the trapezium rule
+        let mut cumulative = vec![0.0; radii.len()];
+
+        for idx in 1..radii.len() {
+            let area = (self.radial_distribution(radii[idx - 1])
+                + self.radial_distribution(radii[idx]))
+                * step
+                / 2.0;
+
+            cumulative[idx] = cumulative[idx - 1] + area;
+        }
+
+        let total = cumulative[RADIAL_STEPS];
+        let bound = (2 * self.l + 1) as f64 / (4.0 * PI);
+
+        (0..count)
+            .map(|_| {
+                let target = rng.next_f64() * total;
+                let idx = cumulative
+                    .partition_point(|&c| c < target)
+                    .clamp(1, RADIAL_STEPS);
+                let fraction =
+                    (target - cumulative[idx - 1]) / (cumulative[idx] - cumulative[idx - 1]);
+                let r = radii[idx - 1] + fraction.clamp(0.0, 1.0) * step;
+
+                let (theta, phi) = loop {
+                    let theta = (2.0 * rng.next_f64() - 1.0).acos();
+                    let phi = 2.0 * PI * rng.next_f64();
+
+                    if rng.next_f64() * bound < self.angular(theta, phi).powi(2) {
+                        break (theta, phi);
+                    }
+                };
+
+                [
+                    r * theta.sin() * phi.cos(),
+                    r * theta.sin() * phi.sin(),
+                    r * theta.cos(),
+                ]
+            })
+            .collect()
+    }
+
+    /// Radius beyond which the radial distribution is negligible
+    fn radial_extent(&self) -> f64 {
+        let n = self.n as f64;
+
+        n * (3.0 * n + 12.0)
+    }
+}
+
+/// Orbital occupied by an electron, ignoring its spin
+///
+/// ```
+/// use chemistru_elements::data::prelude::*;
+/// use chemistru_elements::error::Error;
+/// use chemistru_elements::wavefunction::Orbital;
+///
+/// let electron = QuantumNumbers { n: 2, l: 1, m_l: -1, m_s: 0.5 };
+/// let orbital = Orbital::try_from(electron).unwrap();
+///
+/// assert_eq!(orbital.to_string(), "2p_y");
+///
+/// let invalid = QuantumNumbers { n: 1, l: 1, m_l: 0, m_s: 0.5 };
+///
+/// assert!(matches!(Orbital::try_from(invalid), Err(Error::InvalidAngularMomentum(1))));
+/// ```
+impl TryFrom<QuantumNumbers> for Orbital {
+    type Error = Error;
+
+    fn try_from(electron: QuantumNumbers) -> Result<Self> {
+        Self::new(electron.n, electron.l, electron.m_l)
+    }
+}
+
+impl Subshell {
+    /// Every orbital of the subshell, from m = +l to m = -l
+    pub fn orbitals(&self) -> impl Iterator<Item = Orbital> {
+        let (n, l) = (self.n(), self.l());
+
+        (-(l as i8)..=l as i8)
+            .rev()
+            .map(move |m| Orbital { n, l, m })
+    }
+}
+
+impl fmt::Display for Orbital {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Letters beyond h are not used by any configuration
+        let block = match BLOCKS.get(self.l as usize) {
+            Some(block) => block,
+            None => return write!(f, "{}(l = {}, m = {:+})", self.n, self.l, self.m),
+        };
+
+        let suffix = match (self.l, self.m) {
+            (0, _) => "",
+            (1, 1) => "_x",
+            (1, -1) => "_y",
+            (1, _) => "_z",
+            (2, 2) => "_x²-y²",
+            (2, 1) => "_xz",
+            (2, 0) => "_z²",
+            (2, -1) => "_yz",
+            (2, _) => "_xy",
+            (_, m) => return write!(f, "{}{block}(m = {m:+})", self.n),
+        };
+
+        write!(f, "{}{block}{suffix}", self.n)
+    }
+}
+
+/// high! / low! as a float, without computing either factorial so that it
+/// stays finite for large arguments
+fn factorial_ratio(low: u32, high: u32) -> f64 {
+    (low + 1..=high).map(|k| k as f64).product()
+}
+
+/// Generalised Laguerre polynomial L_k^α(x), by the three term recurrence
+fn laguerre(k: u32, alpha: f64, x: f64) -> f64 {
+    let (mut previous, mut current) = (1.0, 1.0 + alpha - x);
+
+    match k {
+        0 => previous,
+        _ => {
+            for j in 1..k {
+                let j = j as f64;
+                let next =
+                    ((2.0 * j + 1.0 + alpha - x) * current - (j + alpha) * previous) / (j + 1.0);
+
+                (previous, current) = (current, next);
+            }
+
+            current
+        }
+    }
+}
+
+/// Associated Legendre function P_l^m(x) without the Condon–Shortley phase
+fn associated_legendre(l: u32, m: u32, x: f64) -> f64 {
+    let sine = (1.0 - x * x).max(0.0).sqrt();
+
+    // P_m^m = (2m - 1)!! (1 - x²)^(m / 2)
+    let mut p_mm = 1.0;
+
+    for k in 0..m {
+        p_mm *= (2 * k + 1) as f64 * sine;
+    }
+
+    if l == m {
+        return p_mm;
+    }
+
+    let (mut previous, mut current) = (p_mm, x * (2 * m + 1) as f64 * p_mm);
+
+    for degree in m + 2..=l {
+        let next = ((2 * degree - 1) as f64 * x * current - (degree + m - 1) as f64 * previous)
+            / (degree - m) as f64;
+
+        (previous, current) = (current, next);
+    }
+
+    current
+}
+
+/// Small deterministic generator, so samples are reproducible without
+/// depending on a random number crate
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        z ^ (z >> 31)
+    }
+
+    /// Uniform float in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}